@@ -1,5 +1,3 @@
-use std::fmt::Display;
-
 use axum::{
     Router,
     body::Body,
@@ -43,6 +41,7 @@ async fn simple(method: Method, FromForm(mut form): FromForm<Test>) -> Response<
             println!("{:?}", inner.select);
             println!("{:?}", inner.select_optional);
             println!("{:?}", inner.multiselect);
+            println!("{:?}", inner.multiselect_dropdown);
             println!("{:?}", inner.checkbox);
             println!("{:?}", inner.checkbox_optional);
             println!("{:?}", inner.passthrough);
@@ -65,32 +64,7 @@ async fn simple(method: Method, FromForm(mut form): FromForm<Test>) -> Response<
     }
     html! {
         h1 { "Simple Form Example" }
-        form method="POST" {
-            (form.text)
-            (form.text_optional)
-            (form.password)
-            (form.password_optional)
-            (form.number)
-            (form.number_optional)
-            (form.date)
-            (form.date_optional)
-            (form.r#enum)
-            (form.radio)
-            (form.select)
-            (form.select_optional)
-            (form.multiselect)
-            (form.checkbox)
-            (form.checkbox_optional)
-            input type="text" name=(form.passthrough.field_name) value=[form.passthrough.intermediate];
-            input type="text" name=(form.passthrough_option.field_name) value=[form.passthrough_option.intermediate];
-            @for value in &form.passthrough_vec.intermediate {
-                input type="hidden" name=(form.passthrough_vec.field_name) value=(value);
-            }
-            @for value in &form.passthrough_vec_option.intermediate {
-                input type="hidden" name=(form.passthrough_vec_option.field_name) value=(value);
-            }
-            input type="submit";
-        }
+        (form)
     }
     .into_response()
 }
@@ -139,6 +113,14 @@ struct Test {
     )]
     pub multiselect: Vec<Cars>,
 
+    #[multiselect(
+        display_name = "Multiselect Dropdown",
+        options = all,
+        mode = "select",
+        size = 3,
+    )]
+    pub multiselect_dropdown: Vec<Cars>,
+
     #[checkbox(
         display_name = "Required (true) Checkbox",
         help_text = "Helpful text",
@@ -168,41 +150,10 @@ struct Test {
 }
 
 #[derive(Clone, Copy, PartialEq, Hash, Eq, Debug, Selectable)]
+#[selectable(rename_all = "lowercase")]
+#[allow(clippy::upper_case_acronyms)]
 enum Cars {
     Audi,
     BMW,
     Mercedes,
 }
-
-impl Display for Cars {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Cars::Audi => write!(f, "audi"),
-            Cars::BMW => write!(f, "bmw"),
-            Cars::Mercedes => write!(f, "mercedes"),
-        }
-    }
-}
-
-impl std::str::FromStr for Cars {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "audi" => Ok(Cars::Audi),
-            "bmw" => Ok(Cars::BMW),
-            "mercedes" => Ok(Cars::Mercedes),
-            _ => Err(()),
-        }
-    }
-}
-
-impl maud::Render for Cars {
-    fn render(&self) -> maud::Markup {
-        match self {
-            Cars::Audi => html! { "Audi" },
-            Cars::BMW => html! { "BMW" },
-            Cars::Mercedes => html! { "Mercedes" },
-        }
-    }
-}
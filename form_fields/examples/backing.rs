@@ -62,6 +62,7 @@ struct FromDb {
     pub id: Option<i32>,
 }
 
+#[allow(clippy::collapsible_if)]
 async fn backing(
     method: Method,
     State(state): State<AppState>,
@@ -5,16 +5,29 @@ use axum::{
     response::{IntoResponse, Redirect, Response},
     routing::{get, post},
 };
-use form_fields::{from_form::FromForm, selectable::Selectable};
+use axum::extract::State;
+use form_fields::{
+    from_form::{FromForm, OptionsSource},
+    selectable::Selectable,
+};
 use form_fields_macro::FromForm;
 use maud::html;
 use tokio::net::TcpListener;
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
+    let state = AppState {
+        // Load all options from the database or any other source
+        options: vec![
+            "Option 1".to_string(),
+            "Option 2".to_string(),
+            "Option 3".to_string(),
+        ],
+    };
     let app = Router::new()
         .route("/", get(simple))
-        .route("/", post(simple));
+        .route("/", post(simple))
+        .with_state(state);
 
     let listen_addr = "localhost:8080";
     println!("Listening on http://{}", listen_addr);
@@ -23,16 +36,27 @@ async fn main() -> std::io::Result<()> {
     axum::serve(listener, app.into_make_service()).await
 }
 
-async fn simple(method: Method, FromForm(mut form): FromForm<Test>) -> Response<Body> {
-    // Load all options from the database or any other source
-    let options = vec![
-        MyEnum("Option 1".to_string()),
-        MyEnum("Option 2".to_string()),
-        MyEnum("Option 3".to_string()),
-    ];
-    form.radio.descriptor.options = options.clone();
-    form.select.descriptor.options = options.clone();
+#[derive(Clone)]
+struct AppState {
+    options: Vec<String>,
+}
+
+/// Provides the options of both fields before the form is parsed.
+struct MyOptions;
+
+impl OptionsSource<AppState> for MyOptions {
+    type Option = MyEnum;
+
+    async fn options(state: &AppState) -> Vec<MyEnum> {
+        state.options.iter().cloned().map(MyEnum).collect()
+    }
+}
 
+async fn simple(
+    _: State<AppState>,
+    method: Method,
+    FromForm(mut form): FromForm<Test>,
+) -> Response<Body> {
     if method == Method::POST {
         if let Some(inner) = form.inner() {
             println!("{:?}", inner.radio);
@@ -55,10 +79,10 @@ async fn simple(method: Method, FromForm(mut form): FromForm<Test>) -> Response<
 
 #[derive(Debug, FromForm)]
 struct Test {
-    #[radio_button(display_name = "Radio", options = [], default_value = MyEnum(String::new()))]
+    #[radio_button(display_name = "Radio", options_from = MyOptions, default_value = MyEnum(String::new()))]
     pub radio: MyEnum,
 
-    #[select(display_name = "Select", options = [], default_value = MyEnum(String::new()), placeholder = "-- Please choose an option --")]
+    #[select(display_name = "Select", options_from = MyOptions, default_value = MyEnum(String::new()), placeholder = "-- Please choose an option --")]
     pub select: Option<MyEnum>,
 }
 
@@ -1,4 +1,4 @@
-use crate::{Descriptor, FormField};
+use crate::Descriptor;
 
 /// Represents a checkbox input [`<input type="checkbox">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/checkbox).
 #[derive(Debug)]
@@ -11,26 +11,6 @@ impl Descriptor for Checkbox {
     type Value = bool;
     type Intermediate = Option<bool>;
 
-    // NOTE: required for input type checkbox means that it has to be true. This isn't desireable.
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self {
-            required_true,
-            checked,
-        } = &field.descriptor;
-        let prechecked = field.intermediate.unwrap_or(*checked);
-        maud::html! {
-            label {
-                input
-                    type="checkbox"
-                    name=(field.field_name)
-                    value="true"
-                    checked[prechecked]
-                    required[*required_true] {}
-                (field.display_name)
-            }
-        }
-    }
-
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
             *intermediate = None;
@@ -0,0 +1,732 @@
+//! Calendar types supported by the date and time pickers.
+//!
+//! The pickers parse and format the HTML formats themselves and only need a way to
+//! build and take apart the values of the date crate in use. Implementations exist
+//! for `chrono`, `time` and `jiff` behind the features of the same name.
+
+use std::fmt::Debug;
+
+/// A date without a time zone, e.g. `chrono::NaiveDate`, `time::Date` or `jiff::civil::Date`.
+pub trait CivilDate: Copy + Ord + Debug {
+    /// The first year the type can represent.
+    const MIN_YEAR: i32;
+    /// The last year the type can represent.
+    const MAX_YEAR: i32;
+
+    /// Returns `None` if the date doesn't exist.
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self>;
+    fn ymd(&self) -> (i32, u32, u32);
+}
+
+/// A time of day, e.g. `chrono::NaiveTime`, `time::Time` or `jiff::civil::Time`.
+pub trait CivilTime: Copy + Ord + Debug {
+    /// Returns `None` if the time doesn't exist.
+    fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self>;
+    fn hms_nano(&self) -> (u32, u32, u32, u32);
+}
+
+/// A date and time without a time zone, e.g. `chrono::NaiveDateTime`,
+/// `time::PrimitiveDateTime` or `jiff::civil::DateTime`.
+pub trait CivilDateTime: Copy + Ord + Debug {
+    type Date: CivilDate;
+    type Time: CivilTime;
+
+    fn new(date: Self::Date, time: Self::Time) -> Self;
+    fn date(&self) -> Self::Date;
+    fn time(&self) -> Self::Time;
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar.
+const fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Inverse of [`days_from_civil`].
+const fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year as i32, month as u32, day as u32)
+}
+
+/// Days since 1970-01-01.
+pub(crate) fn days<T: CivilDate>(value: &T) -> i64 {
+    let (year, month, day) = value.ymd();
+    days_from_civil(year, month, day)
+}
+
+/// Months since 0000-01.
+pub(crate) fn months<T: CivilDate>(value: &T) -> i64 {
+    let (year, month, _) = value.ymd();
+    i64::from(year) * 12 + i64::from(month) - 1
+}
+
+/// Seconds since midnight.
+pub(crate) fn seconds<T: CivilTime>(value: &T) -> i64 {
+    let (hour, minute, second, _) = value.hms_nano();
+    i64::from(hour * 3600 + minute * 60 + second)
+}
+
+/// Seconds since 1970-01-01T00:00.
+pub(crate) fn datetime_seconds<T: CivilDateTime>(value: &T) -> i64 {
+    days(&value.date()) * 86400 + seconds(&value.time())
+}
+
+/// The ISO week year and week of the date.
+const fn iso_week(days: i64) -> (i32, u32) {
+    // 1970-01-01 was a Thursday, the day deciding the year of a week.
+    let thursday = days - (days + 3).rem_euclid(7) + 3;
+    let (year, _, _) = civil_from_days(thursday);
+    let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+    (year, week as u32)
+}
+
+/// Days since 1970-01-01 of the Monday of the ISO week.
+const fn iso_week_monday(year: i32, week: u32) -> Option<i64> {
+    let january_4th = days_from_civil(year, 1, 4);
+    let monday = january_4th - (january_4th + 3).rem_euclid(7) + week as i64 * 7 - 7;
+    let (monday_year, monday_week) = iso_week(monday);
+    if week >= 1 && monday_year == year && monday_week == week {
+        Some(monday)
+    } else {
+        None
+    }
+}
+
+fn from_days<T: CivilDate>(days: i64) -> Option<T> {
+    let (year, month, day) = civil_from_days(days);
+    T::from_ymd(year, month, day)
+}
+
+/// Returns the Monday of the week of the date.
+pub fn week_monday<T: CivilDate>(value: &T) -> T {
+    let days = days(value);
+    from_days(days - (days + 3).rem_euclid(7)).unwrap_or(*value)
+}
+
+// The parsers below are `const`, so the derive macro can check bounds at compile time
+// with the same code that parses the submitted values.
+
+/// Splits the value at the first of the separators.
+const fn split_once<'a>(value: &'a [u8], separators: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let mut i = 0;
+    while i < value.len() {
+        let mut j = 0;
+        while j < separators.len() {
+            if value[i] == separators[j] {
+                let (head, tail) = value.split_at(i);
+                return Some((head, tail.split_at(1).1));
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Parses a number consisting of `len` ASCII digits, at most 9.
+const fn digits(value: &[u8], len: usize) -> Option<u32> {
+    if value.len() != len || len > 9 {
+        return None;
+    }
+    let mut number = 0;
+    let mut i = 0;
+    while i < len {
+        if !value[i].is_ascii_digit() {
+            return None;
+        }
+        number = number * 10 + (value[i] - b'0') as u32;
+        i += 1;
+    }
+    Some(number)
+}
+
+/// Parses a year of at least four digits.
+const fn parse_year(value: &[u8]) -> Option<i32> {
+    if value.len() < 4 {
+        return None;
+    }
+    let mut year: i32 = 0;
+    let mut i = 0;
+    while i < value.len() {
+        if !value[i].is_ascii_digit() {
+            return None;
+        }
+        let Some(shifted) = year.checked_mul(10) else {
+            return None;
+        };
+        let Some(added) = shifted.checked_add((value[i] - b'0') as i32) else {
+            return None;
+        };
+        year = added;
+        i += 1;
+    }
+    Some(year)
+}
+
+const fn ymd(year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+    if month >= 1 && month <= 12 && day >= 1 && day <= days_in_month(year, month) {
+        Some((year, month, day))
+    } else {
+        None
+    }
+}
+
+const fn parse_date_parts(value: &[u8]) -> Option<(i32, u32, u32)> {
+    let Some((year, rest)) = split_once(value, b"-") else {
+        return None;
+    };
+    let Some((month, day)) = split_once(rest, b"-") else {
+        return None;
+    };
+    match (parse_year(year), digits(month, 2), digits(day, 2)) {
+        (Some(year), Some(month), Some(day)) => ymd(year, month, day),
+        _ => None,
+    }
+}
+
+const fn parse_time_parts(value: &[u8]) -> Option<(u32, u32, u32, u32)> {
+    let Some((hour, rest)) = split_once(value, b":") else {
+        return None;
+    };
+    let (minute, second, nano) = match split_once(rest, b":") {
+        None => (rest, Some(0), Some(0)),
+        Some((minute, rest)) => match split_once(rest, b".") {
+            None => (minute, digits(rest, 2), Some(0)),
+            Some((second, fraction)) => {
+                let nano = match digits(fraction, fraction.len()) {
+                    Some(nano) if !fraction.is_empty() => {
+                        Some(nano * 10u32.pow(9 - fraction.len() as u32))
+                    }
+                    _ => None,
+                };
+                (minute, digits(second, 2), nano)
+            }
+        },
+    };
+    match (digits(hour, 2), digits(minute, 2), second, nano) {
+        (Some(hour), Some(minute), Some(second), Some(nano))
+            if hour < 24 && minute < 60 && second < 60 =>
+        {
+            Some((hour, minute, second, nano))
+        }
+        _ => None,
+    }
+}
+
+/// Parses a date formatted as `YYYY-MM-DD` into its year, month and day.
+pub const fn date_parts(value: &str) -> Option<(i32, u32, u32)> {
+    parse_date_parts(value.as_bytes())
+}
+
+/// Parses a time formatted as `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`
+/// into its hour, minute, second and nanosecond.
+pub const fn time_parts(value: &str) -> Option<(u32, u32, u32, u32)> {
+    parse_time_parts(value.as_bytes())
+}
+
+/// Parses a date and time formatted as `YYYY-MM-DDTHH:MM[:SS]` into its parts.
+/// Browsers may use a space instead of `T`.
+#[allow(clippy::type_complexity)]
+pub const fn datetime_parts(value: &str) -> Option<((i32, u32, u32), (u32, u32, u32, u32))> {
+    let Some((date, time)) = split_once(value.as_bytes(), b"T ") else {
+        return None;
+    };
+    match (parse_date_parts(date), parse_time_parts(time)) {
+        (Some(date), Some(time)) => Some((date, time)),
+        _ => None,
+    }
+}
+
+/// Parses a month formatted as `YYYY-MM` into the parts of its first day.
+pub const fn month_parts(value: &str) -> Option<(i32, u32, u32)> {
+    let Some((year, month)) = split_once(value.as_bytes(), b"-") else {
+        return None;
+    };
+    match (parse_year(year), digits(month, 2)) {
+        (Some(year), Some(month)) => ymd(year, month, 1),
+        _ => None,
+    }
+}
+
+/// Parses an ISO week formatted as `YYYY-Www` into the parts of its Monday.
+pub const fn week_parts(value: &str) -> Option<(i32, u32, u32)> {
+    let Some((year, week)) = split_once(value.as_bytes(), b"-") else {
+        return None;
+    };
+    let Some((b'W', week)) = week.split_first() else {
+        return None;
+    };
+    let (Some(year), Some(week)) = (parse_year(year), digits(week, 2)) else {
+        return None;
+    };
+    match iso_week_monday(year, week) {
+        Some(monday) => Some(civil_from_days(monday)),
+        None => None,
+    }
+}
+
+/// Unwraps a bound the derive macro parses at compile time.
+#[doc(hidden)]
+pub const fn __parsed<T: Copy>(parsed: Option<T>, message: &str) -> T {
+    match parsed {
+        Some(parsed) => parsed,
+        None => panic!("{}", message),
+    }
+}
+
+/// Checks at compile time that the date type can represent a bound of the derive macro.
+#[doc(hidden)]
+pub const fn __in_range<T: CivilDate>(date: (i32, u32, u32)) -> (i32, u32, u32) {
+    assert!(
+        date.0 >= T::MIN_YEAR && date.0 <= T::MAX_YEAR,
+        "the year is out of range for the date type"
+    );
+    date
+}
+
+/// Parses a date formatted as `YYYY-MM-DD`.
+pub fn parse_date<T: CivilDate>(value: &str) -> Option<T> {
+    let (year, month, day) = date_parts(value)?;
+    T::from_ymd(year, month, day)
+}
+
+/// Parses a time formatted as `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`.
+pub fn parse_time<T: CivilTime>(value: &str) -> Option<T> {
+    let (hour, minute, second, nano) = time_parts(value)?;
+    T::from_hms_nano(hour, minute, second, nano)
+}
+
+/// Parses a date and time formatted as `YYYY-MM-DDTHH:MM[:SS]`.
+/// Browsers may use a space instead of `T`.
+pub fn parse_datetime<T: CivilDateTime>(value: &str) -> Option<T> {
+    let ((year, month, day), (hour, minute, second, nano)) = datetime_parts(value)?;
+    Some(T::new(
+        T::Date::from_ymd(year, month, day)?,
+        T::Time::from_hms_nano(hour, minute, second, nano)?,
+    ))
+}
+
+/// Parses a month formatted as `YYYY-MM` into its first day.
+pub fn parse_month<T: CivilDate>(value: &str) -> Option<T> {
+    let (year, month, day) = month_parts(value)?;
+    T::from_ymd(year, month, day)
+}
+
+/// Parses an ISO week formatted as `YYYY-Www` into its Monday.
+pub fn parse_week<T: CivilDate>(value: &str) -> Option<T> {
+    let (year, month, day) = week_parts(value)?;
+    T::from_ymd(year, month, day)
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+pub fn format_date<T: CivilDate>(value: &T) -> String {
+    let (year, month, day) = value.ymd();
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Formats the time as `HH:MM`, adding seconds and fractions only if they are set.
+pub fn format_time<T: CivilTime>(value: &T) -> String {
+    match value.hms_nano() {
+        (hour, minute, 0, 0) => format!("{hour:02}:{minute:02}"),
+        (hour, minute, second, 0) => format!("{hour:02}:{minute:02}:{second:02}"),
+        (hour, minute, second, nano) if nano % 1_000_000 == 0 => {
+            format!("{hour:02}:{minute:02}:{second:02}.{:03}", nano / 1_000_000)
+        }
+        (hour, minute, second, nano) => format!("{hour:02}:{minute:02}:{second:02}.{nano:09}"),
+    }
+}
+
+/// Formats the date and time as `YYYY-MM-DDTHH:MM`, adding seconds only if they are set.
+pub fn format_datetime<T: CivilDateTime>(value: &T) -> String {
+    format!(
+        "{}T{}",
+        format_date(&value.date()),
+        format_time(&value.time())
+    )
+}
+
+/// Formats the month of the date as `YYYY-MM`.
+pub fn format_month<T: CivilDate>(value: &T) -> String {
+    let (year, month, _) = value.ymd();
+    format!("{year:04}-{month:02}")
+}
+
+/// Formats the ISO week of the date as `YYYY-Www`.
+pub fn format_week<T: CivilDate>(value: &T) -> String {
+    let (year, week) = iso_week(days(value));
+    format!("{year:04}-W{week:02}")
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    use super::{CivilDate, CivilDateTime, CivilTime};
+
+    impl CivilDate for NaiveDate {
+        // The years of `NaiveDate::MIN` and `NaiveDate::MAX`.
+        const MIN_YEAR: i32 = -262_143;
+        const MAX_YEAR: i32 = 262_142;
+
+        fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+            NaiveDate::from_ymd_opt(year, month, day)
+        }
+
+        fn ymd(&self) -> (i32, u32, u32) {
+            (self.year(), self.month(), self.day())
+        }
+    }
+
+    impl CivilTime for NaiveTime {
+        fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+            // chrono uses nanoseconds above one second for leap seconds.
+            (nano < 1_000_000_000)
+                .then(|| NaiveTime::from_hms_nano_opt(hour, minute, second, nano))
+                .flatten()
+        }
+
+        fn hms_nano(&self) -> (u32, u32, u32, u32) {
+            (self.hour(), self.minute(), self.second(), self.nanosecond())
+        }
+    }
+
+    impl CivilDateTime for NaiveDateTime {
+        type Date = NaiveDate;
+        type Time = NaiveTime;
+
+        fn new(date: NaiveDate, time: NaiveTime) -> Self {
+            date.and_time(time)
+        }
+
+        fn date(&self) -> NaiveDate {
+            NaiveDateTime::date(self)
+        }
+
+        fn time(&self) -> NaiveTime {
+            NaiveDateTime::time(self)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use super::{CivilDate, CivilDateTime, CivilTime};
+
+    impl CivilDate for Date {
+        const MIN_YEAR: i32 = Date::MIN.year();
+        const MAX_YEAR: i32 = Date::MAX.year();
+
+        fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+            let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+            Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+        }
+
+        fn ymd(&self) -> (i32, u32, u32) {
+            (
+                self.year(),
+                u8::from(self.month()).into(),
+                self.day().into(),
+            )
+        }
+    }
+
+    impl CivilTime for Time {
+        fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+            Time::from_hms_nano(
+                u8::try_from(hour).ok()?,
+                u8::try_from(minute).ok()?,
+                u8::try_from(second).ok()?,
+                nano,
+            )
+            .ok()
+        }
+
+        fn hms_nano(&self) -> (u32, u32, u32, u32) {
+            let (hour, minute, second, nano) = self.as_hms_nano();
+            (hour.into(), minute.into(), second.into(), nano)
+        }
+    }
+
+    impl CivilDateTime for PrimitiveDateTime {
+        type Date = Date;
+        type Time = Time;
+
+        fn new(date: Date, time: Time) -> Self {
+            PrimitiveDateTime::new(date, time)
+        }
+
+        fn date(&self) -> Date {
+            PrimitiveDateTime::date(*self)
+        }
+
+        fn time(&self) -> Time {
+            PrimitiveDateTime::time(*self)
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_impl {
+    use jiff::civil::{Date, DateTime, Time};
+
+    use super::{CivilDate, CivilDateTime, CivilTime};
+
+    impl CivilDate for Date {
+        // The years of `Date::MIN` and `Date::MAX`, spelled out since `Date::year` isn't const.
+        const MIN_YEAR: i32 = -9999;
+        const MAX_YEAR: i32 = 9999;
+
+        fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+            Date::new(
+                i16::try_from(year).ok()?,
+                i8::try_from(month).ok()?,
+                i8::try_from(day).ok()?,
+            )
+            .ok()
+        }
+
+        fn ymd(&self) -> (i32, u32, u32) {
+            (self.year().into(), self.month() as u32, self.day() as u32)
+        }
+    }
+
+    impl CivilTime for Time {
+        fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+            Time::new(
+                i8::try_from(hour).ok()?,
+                i8::try_from(minute).ok()?,
+                i8::try_from(second).ok()?,
+                i32::try_from(nano).ok()?,
+            )
+            .ok()
+        }
+
+        fn hms_nano(&self) -> (u32, u32, u32, u32) {
+            (
+                self.hour() as u32,
+                self.minute() as u32,
+                self.second() as u32,
+                self.subsec_nanosecond() as u32,
+            )
+        }
+    }
+
+    impl CivilDateTime for DateTime {
+        type Date = Date;
+        type Time = Time;
+
+        fn new(date: Date, time: Time) -> Self {
+            DateTime::from_parts(date, time)
+        }
+
+        fn date(&self) -> Date {
+            DateTime::date(*self)
+        }
+
+        fn time(&self) -> Time {
+            DateTime::time(*self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "maud", any(feature = "time", feature = "jiff")))]
+    use crate::{self as form_fields, render::maud::Maud};
+    #[cfg(all(feature = "maud", any(feature = "time", feature = "jiff")))]
+    use form_fields_macro::FromForm;
+
+    #[test]
+    fn days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in [-800_000, -1, 0, 11017, 19_999, 2_000_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(iso_week(days_from_civil(2024, 12, 30)), (2025, 1));
+        assert_eq!(iso_week(days_from_civil(2021, 1, 3)), (2020, 53));
+        assert_eq!(
+            iso_week_monday(2020, 53),
+            Some(days_from_civil(2020, 12, 28))
+        );
+        assert_eq!(iso_week_monday(2024, 53), None);
+        assert_eq!(iso_week_monday(2024, 0), None);
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(date_parts("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(date_parts("2023-02-29"), None);
+        assert_eq!(date_parts("10000-01-01"), Some((10000, 1, 1)));
+        assert_eq!(date_parts("99999999999-01-01"), None);
+        assert_eq!(time_parts("23:59:30.5"), Some((23, 59, 30, 500_000_000)));
+        assert_eq!(time_parts("24:00"), None);
+        assert_eq!(time_parts("09:05:07."), None);
+        assert_eq!(
+            datetime_parts("2024-01-01 08:00"),
+            datetime_parts("2024-01-01T08:00")
+        );
+        assert_eq!(month_parts("2024-13"), None);
+        assert_eq!(week_parts("2020-W53"), Some((2020, 12, 28)));
+        assert_eq!(week_parts("2024-W53"), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_types() {
+        use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(parse_date::<NaiveDate>("2024-02-29"), Some(date));
+        assert_eq!(parse_date::<NaiveDate>("2023-02-29"), None);
+        assert_eq!(parse_date::<NaiveDate>("2024-2-29"), None);
+        assert_eq!(format_date(&date), "2024-02-29");
+        assert_eq!(format_month(&date), "2024-02");
+        assert_eq!(format_week(&date), "2024-W09");
+        assert_eq!(
+            week_monday(&date),
+            NaiveDate::from_ymd_opt(2024, 2, 26).unwrap()
+        );
+
+        let time = NaiveTime::from_hms_milli_opt(9, 5, 7, 250).unwrap();
+        assert_eq!(parse_time::<NaiveTime>("09:05:07.25"), Some(time));
+        assert_eq!(parse_time::<NaiveTime>("09:05:60"), None);
+        assert_eq!(format_time(&time), "09:05:07.250");
+
+        let datetime = parse_datetime::<NaiveDateTime>("2024-02-29T09:05").unwrap();
+        assert_eq!(format_datetime(&datetime), "2024-02-29T09:05");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_types() {
+        use time::{Date, Month, PrimitiveDateTime, Time};
+
+        let date = Date::from_calendar_date(2025, Month::January, 1).unwrap();
+        assert_eq!(
+            parse_week::<Date>("2025-W01"),
+            date.previous_day().and_then(|d| d.previous_day())
+        );
+        assert_eq!(parse_date::<Date>("2025-01-01"), Some(date));
+        assert_eq!(parse_date::<Date>("2025-13-01"), None);
+
+        let time = Time::from_hms(23, 59, 30).unwrap();
+        assert_eq!(parse_time::<Time>("23:59:30"), Some(time));
+        let datetime = parse_datetime::<PrimitiveDateTime>("2025-01-01 23:59:30").unwrap();
+        assert_eq!(datetime, PrimitiveDateTime::new(date, time));
+        assert_eq!(format_datetime(&datetime), "2025-01-01T23:59:30");
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_types() {
+        use jiff::civil::{Date, DateTime, Time, date, time};
+
+        assert_eq!(parse_month::<Date>("2024-07"), Some(date(2024, 7, 1)));
+        assert_eq!(parse_date::<Date>("2024-06-31"), None);
+        assert_eq!(parse_time::<Time>("07:30"), Some(time(7, 30, 0, 0)));
+        let datetime = parse_datetime::<DateTime>("2024-07-01T07:30:15").unwrap();
+        assert_eq!(datetime, date(2024, 7, 1).at(7, 30, 15, 0));
+        assert_eq!(format_week(&datetime.date()), "2024-W27");
+
+        assert_eq!(i32::from(Date::MIN.year()), <Date as CivilDate>::MIN_YEAR);
+        assert_eq!(i32::from(Date::MAX.year()), <Date as CivilDate>::MAX_YEAR);
+    }
+
+    #[cfg(all(feature = "maud", feature = "time"))]
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Appointment {
+        #[date_select(display_name = "Day", min = "2024-02-29")]
+        day: time::Date,
+        #[datetime_select(display_name = "Reminder", max = "2024-12-31T23:59:30")]
+        reminder: Option<time::PrimitiveDateTime>,
+    }
+
+    #[cfg(all(feature = "maud", feature = "time"))]
+    #[test]
+    fn render_time_crate() {
+        let mut form = AppointmentFormSpec::new();
+        form_fields::from_form::FormSpec::parse_field(&mut form, "day", "2024-03-01");
+        form_fields::from_form::FormSpec::parse_field(&mut form, "reminder", "2024-02-29T08:00");
+        assert_eq!(
+            form.reminder.render_with::<Maud>().into_string(),
+            "<label for=\"appointment-reminder\">Reminder</label>\
+            <input type=\"datetime-local\" id=\"appointment-reminder\" name=\"reminder\" \
+            value=\"2024-02-29T08:00\" max=\"2024-12-31T23:59:30\"></input>"
+        );
+
+        let appointment = form.inner().unwrap();
+        assert_eq!(
+            appointment.day,
+            time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap()
+        );
+    }
+
+    #[cfg(all(feature = "maud", feature = "jiff"))]
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Trip {
+        #[week_select(display_name = "Week", min = "2021-W01")]
+        week: jiff::civil::Date,
+        #[time_select(display_name = "Departure", step = 60)]
+        departure: jiff::civil::Time,
+    }
+
+    #[cfg(all(feature = "maud", feature = "jiff"))]
+    #[test]
+    fn render_jiff_crate() {
+        let mut form = TripFormSpec::new();
+        assert_eq!(
+            form.week.descriptor.min,
+            Some(jiff::civil::date(2021, 1, 4))
+        );
+        form_fields::from_form::FormSpec::parse_field(&mut form, "week", "2021-W02");
+        form_fields::from_form::FormSpec::parse_field(&mut form, "departure", "06:45");
+        assert_eq!(
+            form.week.render_with::<Maud>().into_string(),
+            "<label for=\"trip-week\">Week</label>\
+            <input type=\"week\" id=\"trip-week\" name=\"week\" value=\"2021-W02\" min=\"2021-W01\" required></input>"
+        );
+
+        let trip = form.inner().unwrap();
+        assert_eq!(trip.week, jiff::civil::date(2021, 1, 11));
+        assert_eq!(trip.departure, jiff::civil::time(6, 45, 0, 0));
+    }
+}
@@ -1,34 +1,19 @@
-use std::str::FromStr;
-
-use chrono::NaiveDate;
-
-use crate::{Descriptor, FormField, validation_value::Value};
+use super::civil::{self, CivilDate};
+use crate::{Descriptor, validation_value::Value};
 
 /// Represents a date picker input [`<input type="date">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/date).
 #[derive(Debug)]
-pub struct DatePicker {
-    pub min: Option<NaiveDate>,
-    pub max: Option<NaiveDate>,
+pub struct DatePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> {
+    pub min: Option<T>,
+    pub max: Option<T>,
 }
 
-impl Descriptor for DatePicker {
-    type Value = NaiveDate;
-    type Intermediate = Value<NaiveDate>;
-
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self { min, max } = &field.descriptor;
-        let value = field.intermediate.inner();
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            input
-                type="date"
-                name=(field.field_name)
-                value=[value.map(|v| v.format("%Y-%m-%d").to_string())]
-                min=[min.map(|v| v.format("%Y-%m-%d").to_string())]
-                max=[max.map(|v| v.format("%Y-%m-%d").to_string())]
-                required[field.required] {}
-        }
-    }
+impl<T: CivilDate> Descriptor for DatePicker<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
 
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
@@ -36,7 +21,7 @@ impl Descriptor for DatePicker {
             return;
         }
 
-        let Ok(parsed_value) = NaiveDate::from_str(value) else {
+        let Some(parsed_value) = civil::parse_date(value) else {
             *intermediate = Value::Failure(value.to_string(), "Invalid date".to_string());
             return;
         };
@@ -67,8 +52,10 @@ impl Descriptor for DatePicker {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod test {
+    use chrono::NaiveDate;
+
     use super::*;
 
     #[test]
@@ -93,7 +80,7 @@ mod test {
 
     #[test]
     fn validate() {
-        let date_picker = DatePicker {
+        let date_picker: DatePicker = DatePicker {
             min: NaiveDate::from_ymd_opt(2020, 1, 1),
             max: NaiveDate::from_ymd_opt(2023, 12, 31),
         };
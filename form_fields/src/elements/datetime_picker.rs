@@ -0,0 +1,124 @@
+use super::civil::{self, CivilDateTime, CivilTime};
+use crate::{Descriptor, validation_value::Value};
+
+/// Represents a local date and time picker input [`<input type="datetime-local">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/datetime-local).
+/// Accepts values with and without seconds.
+#[derive(Debug)]
+pub struct DateTimePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDateTime,
+    #[cfg(not(feature = "chrono"))] T,
+> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// Step in seconds, counted from `min` or 1970-01-01T00:00. The browser defaults to 60.
+    pub step: Option<u32>,
+}
+
+impl<T: CivilDateTime> Descriptor for DateTimePicker<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        let Some(parsed_value) = civil::parse_datetime(value) else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid date and time".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value)
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = intermediate.inner().ok_or("Value is required")?;
+
+        if let Some(min) = &self.min
+            && value < min
+        {
+            return Err("Value is less than min");
+        }
+
+        if let Some(max) = &self.max
+            && value > max
+        {
+            return Err("Value exceeds max");
+        }
+
+        if let Some(step) = self.step {
+            let (base, base_nano) = self.min.as_ref().map_or((0, 0), |min| {
+                (civil::datetime_seconds(min), min.time().hms_nano().3)
+            });
+            let seconds = civil::datetime_seconds(value) - base;
+            if value.time().hms_nano().3 != base_nano || seconds % i64::from(step.max(1)) != 0 {
+                return Err("Value doesn't match the step");
+            }
+        }
+
+        Ok(*value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Value::Success(value)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+
+    fn datetime(h: u32, m: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(h, m, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let picker = DateTimePicker {
+            min: None,
+            max: None,
+            step: None,
+        };
+        let mut intermediate = Value::None;
+        picker.parse("2024-03-01T09:30", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&datetime(9, 30, 0)));
+
+        picker.parse("2024-03-01T09:30:15", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&datetime(9, 30, 15)));
+
+        picker.parse("2024-03-01 09:30", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&datetime(9, 30, 0)));
+
+        picker.parse("2024-03-01", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+    }
+
+    #[test]
+    fn validate() {
+        let picker = DateTimePicker {
+            min: Some(datetime(9, 0, 0)),
+            max: Some(datetime(17, 0, 0)),
+            step: Some(30 * 60),
+        };
+
+        assert!(picker.validate(&Value::Success(datetime(9, 30, 0))).is_ok());
+        assert_eq!(
+            picker.validate(&Value::Success(datetime(8, 0, 0))),
+            Err("Value is less than min")
+        );
+        assert_eq!(
+            picker.validate(&Value::Success(datetime(9, 30, 1))),
+            Err("Value doesn't match the step")
+        );
+        assert_eq!(
+            civil::format_datetime(&datetime(9, 30, 0)),
+            "2024-03-01T09:30"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+pub use rust_decimal::Decimal;
+
+use super::hints::InputHints;
+use crate::{Descriptor, validation_value::Value};
+
+/// Represents a number input field [`<input type="number">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/number)
+/// for exact decimal values, e.g. prices.
+#[derive(Debug, Default)]
+pub struct DecimalField {
+    pub min: Option<Decimal>,
+    pub max: Option<Decimal>,
+    /// Step, counted from `min` or zero.
+    pub step: Option<Decimal>,
+    /// Maximum number of decimal places.
+    pub scale: Option<u32>,
+    pub hints: InputHints,
+}
+
+impl DecimalField {
+    /// The `step` attribute of the input.
+    /// Without a step, it is derived from the scale, so the browser accepts every valid value.
+    pub fn step_attribute(&self) -> String {
+        match (self.step, self.scale) {
+            (Some(step), _) => step.normalize().to_string(),
+            (None, Some(scale)) => Decimal::new(1, scale.min(Decimal::MAX_SCALE)).to_string(),
+            (None, None) => "any".to_string(),
+        }
+    }
+}
+
+impl Descriptor for DecimalField {
+    type Value = Decimal;
+    type Intermediate = Value<Decimal>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        // Browsers submit exponents as typed, e.g. `1e3`.
+        let Ok(parsed_value) =
+            Decimal::from_str_exact(value).or_else(|_| Decimal::from_scientific(value))
+        else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid number".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value);
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = match intermediate {
+            Value::Success(value) => value,
+            Value::Failure(_, _) => return Err("Invalid number"),
+            Value::None => return Err("Value is required"),
+        };
+
+        if let Some(min) = self.min
+            && *value < min
+        {
+            return Err("Value is less than min");
+        }
+
+        if let Some(max) = self.max
+            && *value > max
+        {
+            return Err("Value exceeds max");
+        }
+
+        if let Some(scale) = self.scale
+            && value.normalize().scale() > scale
+        {
+            return Err("Value has too many decimal places");
+        }
+
+        if let Some(step) = self.step
+            && !step.is_zero()
+        {
+            let remainder = value
+                .checked_sub(self.min.unwrap_or_default())
+                .and_then(|offset| offset.checked_rem(step))
+                .ok_or("Value is out of range")?;
+            if !remainder.is_zero() {
+                return Err("Value doesn't match the step");
+            }
+        }
+
+        Ok(*value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Value::Success(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let decimal_field = DecimalField::default();
+        let mut intermediate = Value::None;
+
+        decimal_field.parse("19.90", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&Decimal::new(1990, 2)));
+
+        decimal_field.parse("1.5e2", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&Decimal::new(150, 0)));
+
+        decimal_field.parse("1,5", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+    }
+
+    #[test]
+    fn validate() {
+        let decimal_field = DecimalField {
+            min: Some(Decimal::new(50, 2)),
+            max: Some(Decimal::new(100, 0)),
+            step: Some(Decimal::new(25, 2)),
+            scale: Some(2),
+            ..Default::default()
+        };
+
+        assert!(
+            decimal_field
+                .validate(&Value::Success(Decimal::new(275, 2)))
+                .is_ok()
+        );
+        assert!(
+            decimal_field
+                .validate(&Value::Success(Decimal::new(27500, 4)))
+                .is_ok()
+        );
+        assert_eq!(
+            decimal_field.validate(&Value::Success(Decimal::new(25, 2))),
+            Err("Value is less than min")
+        );
+        assert_eq!(
+            decimal_field.validate(&Value::Success(Decimal::new(2755, 3))),
+            Err("Value has too many decimal places")
+        );
+        assert_eq!(
+            decimal_field.validate(&Value::Success(Decimal::new(260, 2))),
+            Err("Value doesn't match the step")
+        );
+    }
+
+    #[test]
+    fn validate_overflow() {
+        let decimal_field = DecimalField {
+            min: Some(Decimal::MIN),
+            step: Some(Decimal::new(1, 2)),
+            ..Default::default()
+        };
+        assert_eq!(
+            decimal_field.validate(&Value::Success(Decimal::MAX)),
+            Err("Value is out of range")
+        );
+    }
+
+    #[test]
+    fn validate_invalid() {
+        let decimal_field = DecimalField::default();
+        let mut intermediate = Value::None;
+        decimal_field.parse("abc", &mut intermediate);
+        assert_eq!(decimal_field.validate(&intermediate), Err("Invalid number"));
+        assert_eq!(
+            decimal_field.validate(&Value::None),
+            Err("Value is required")
+        );
+    }
+
+    #[test]
+    fn load() {
+        let decimal_field = DecimalField {
+            step: Some(Decimal::new(5, 2)),
+            ..Default::default()
+        };
+        let intermediate = decimal_field.load(Decimal::new(1995, 2));
+        assert_eq!(
+            decimal_field.validate(&intermediate),
+            Ok(Decimal::new(1995, 2))
+        );
+    }
+
+    #[test]
+    fn step_attribute() {
+        let mut decimal_field = DecimalField::default();
+        assert_eq!(decimal_field.step_attribute(), "any");
+        decimal_field.scale = Some(2);
+        assert_eq!(decimal_field.step_attribute(), "0.01");
+        decimal_field.step = Some(Decimal::new(500, 2));
+        assert_eq!(decimal_field.step_attribute(), "5");
+    }
+}
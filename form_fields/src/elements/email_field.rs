@@ -0,0 +1,253 @@
+use super::hints::InputHints;
+use crate::Descriptor;
+
+/// Represents an email input field [`<input type="email">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/email).
+/// The syntax is checked like the browser does.
+/// `EmailField<Vec<String>>` accepts a comma-separated list of addresses.
+#[derive(Debug)]
+pub struct EmailField<T> {
+    pub placeholder: Option<String>,
+    pub max_length: Option<usize>,
+    /// Lowercases the domain, keeping the local part as entered.
+    pub lowercase_domain: bool,
+    /// Converts internationalized domains to punycode.
+    #[cfg(feature = "idna")]
+    pub punycode: bool,
+    pub hints: InputHints,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> Default for EmailField<T> {
+    fn default() -> Self {
+        Self {
+            placeholder: None,
+            max_length: None,
+            lowercase_domain: false,
+            #[cfg(feature = "idna")]
+            punycode: false,
+            hints: InputHints::default(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// The value of an email field, a single address or a list of addresses.
+pub trait EmailValue {
+    /// Whether the browser should accept multiple addresses.
+    const MULTIPLE: bool;
+}
+
+impl EmailValue for String {
+    const MULTIPLE: bool = false;
+}
+
+impl EmailValue for Vec<String> {
+    const MULTIPLE: bool = true;
+}
+
+impl<T: EmailValue> EmailField<T> {
+    /// Whether the browser should accept multiple addresses.
+    pub fn multiple(&self) -> bool {
+        T::MULTIPLE
+    }
+}
+
+impl<T> EmailField<T> {
+    fn normalize(&self, address: &str) -> Result<String, &'static str> {
+        let (local, domain) = address.rsplit_once('@').ok_or("Invalid email address")?;
+
+        #[cfg(feature = "idna")]
+        let domain = if self.punycode {
+            idna::domain_to_ascii(domain).map_err(|_| "Invalid email address")?
+        } else {
+            domain.to_string()
+        };
+        #[cfg(not(feature = "idna"))]
+        let domain = domain.to_string();
+        let domain = if self.lowercase_domain {
+            domain.to_ascii_lowercase()
+        } else {
+            domain
+        };
+
+        if !is_valid_local_part(local) || !is_valid_domain(&domain) {
+            return Err("Invalid email address");
+        }
+
+        Ok(format!("{local}@{domain}"))
+    }
+
+    fn check_length<'a>(&self, intermediate: &'a Option<String>) -> Result<&'a str, &'static str> {
+        let value = intermediate.as_deref().ok_or("Value is required")?;
+
+        if let Some(max_length) = self.max_length
+            && value.len() > max_length
+        {
+            return Err("Value exceeds max length");
+        }
+
+        Ok(value)
+    }
+}
+
+/// Characters allowed in the local part by the HTML specification.
+fn is_valid_local_part(local: &str) -> bool {
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c))
+}
+
+/// Every label is 1 to 63 alphanumeric characters or hyphens,
+/// without a hyphen at the start or end.
+fn is_valid_domain(domain: &str) -> bool {
+    domain.split('.').all(|label| {
+        (1..=63).contains(&label.len())
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+impl Descriptor for EmailField<String> {
+    type Value = String;
+    type Intermediate = Option<String>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        let value = value.trim();
+        if value.is_empty() {
+            *intermediate = None;
+            return;
+        }
+
+        *intermediate = Some(value.to_string());
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = self.check_length(intermediate)?;
+        self.normalize(value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Some(value)
+    }
+}
+
+impl Descriptor for EmailField<Vec<String>> {
+    type Value = Vec<String>;
+    type Intermediate = Option<String>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        let value = value.trim();
+        if value.split(',').all(|address| address.trim().is_empty()) {
+            *intermediate = None;
+            return;
+        }
+
+        *intermediate = Some(value.to_string());
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = self.check_length(intermediate)?;
+        let addresses = value
+            .split(',')
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| self.normalize(address))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if addresses.is_empty() {
+            return Err("Value is required");
+        }
+
+        Ok(addresses)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Some(value.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        let descriptor = EmailField::<String>::default();
+        let valid = |value: &str| descriptor.validate(&Some(value.to_string()));
+
+        assert_eq!(valid("user@example.com"), Ok("user@example.com".to_string()));
+        assert_eq!(
+            valid("first.last+tag@sub.example.org"),
+            Ok("first.last+tag@sub.example.org".to_string())
+        );
+        assert_eq!(valid("user@localhost"), Ok("user@localhost".to_string()));
+        assert!(valid("user").is_err());
+        assert!(valid("@example.com").is_err());
+        assert!(valid("user@").is_err());
+        assert!(valid("user@-example.com").is_err());
+        assert!(valid("user@example..com").is_err());
+        assert!(valid("us er@example.com").is_err());
+        assert!(valid("user@bücher.de").is_err());
+        assert!(descriptor.validate(&None).is_err());
+    }
+
+    #[test]
+    fn lowercase_domain() {
+        let descriptor = EmailField::<String> {
+            lowercase_domain: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            descriptor.validate(&Some("John.Doe@Example.COM".to_string())),
+            Ok("John.Doe@example.com".to_string())
+        );
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn punycode() {
+        let descriptor = EmailField::<String> {
+            punycode: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            descriptor.validate(&Some("info@Bücher.de".to_string())),
+            Ok("info@xn--bcher-kva.de".to_string())
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        let descriptor = EmailField::<Vec<String>>::default();
+        assert!(descriptor.multiple());
+        let mut intermediate = None;
+        descriptor.parse(" a@example.com, b@example.org ,", &mut intermediate);
+        assert_eq!(
+            descriptor.validate(&intermediate),
+            Ok(vec!["a@example.com".to_string(), "b@example.org".to_string()])
+        );
+
+        descriptor.parse("a@example.com, b", &mut intermediate);
+        assert!(descriptor.validate(&intermediate).is_err());
+
+        // Separators only leave the field empty.
+        descriptor.parse(" , ,", &mut intermediate);
+        assert_eq!(intermediate, None);
+        assert_eq!(descriptor.validate(&intermediate), Err("Value is required"));
+        assert_eq!(
+            descriptor.validate(&Some(",".to_string())),
+            Err("Value is required")
+        );
+    }
+
+    #[test]
+    fn load() {
+        let descriptor = EmailField::<Vec<String>>::default();
+        let emails = vec!["a@example.com".to_string(), "b@example.org".to_string()];
+        let intermediate = descriptor.load(emails.clone());
+        assert_eq!(intermediate.as_deref(), Some("a@example.com,b@example.org"));
+        assert_eq!(descriptor.validate(&intermediate), Ok(emails));
+    }
+}
@@ -0,0 +1,70 @@
+/// HTML attributes that help the browser with entering a value,
+/// e.g. for autofill and password managers. They are not validated on the server.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InputHints {
+    /// [`autocomplete`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete), e.g. `username` or `new-password`.
+    pub autocomplete: Option<String>,
+    /// [`inputmode`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inputmode), e.g. `numeric` or `email`.
+    pub inputmode: Option<String>,
+    pub spellcheck: Option<bool>,
+    /// [`autocapitalize`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autocapitalize), e.g. `off` or `words`.
+    pub autocapitalize: Option<String>,
+    pub readonly: bool,
+    /// Visible width of the input in characters.
+    pub size: Option<usize>,
+}
+
+impl InputHints {
+    /// Value of the `spellcheck` attribute.
+    pub fn spellcheck(&self) -> Option<&'static str> {
+        self.spellcheck
+            .map(|spellcheck| if spellcheck { "true" } else { "false" })
+    }
+}
+
+/// A regular expression the whole value has to match, like the HTML
+/// [`pattern`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/pattern) attribute.
+/// The browser and the server use different regex engines, so stick to the common syntax.
+/// Can only be created with the `regex` feature.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    #[cfg(feature = "regex")]
+    regex: regex::Regex,
+}
+
+impl Pattern {
+    #[cfg(feature = "regex")]
+    pub fn new(source: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            source: source.to_string(),
+            regex: regex::Regex::new(&format!("^(?:{source})$"))?,
+        })
+    }
+
+    /// The pattern as written, used as the value of the `pattern` attribute.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
+
+#[cfg(all(test, feature = "regex"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pattern_matches_whole_value() {
+        let pattern = Pattern::new("[a-z]+|[0-9]+").unwrap();
+        assert_eq!(pattern.as_str(), "[a-z]+|[0-9]+");
+        assert!(pattern.is_match("abc"));
+        assert!(pattern.is_match("123"));
+        assert!(!pattern.is_match("abc123"));
+        assert!(!pattern.is_match(""));
+    }
+}
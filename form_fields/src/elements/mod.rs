@@ -1,21 +1,60 @@
 pub mod checkbox;
-#[cfg(feature = "chrono")]
+pub mod civil;
 pub mod date_picker;
+pub mod datetime_picker;
+#[cfg(feature = "decimal")]
+pub mod decimal_field;
+pub mod email_field;
+pub mod hints;
+pub mod month_picker;
 pub mod multiselect;
 pub mod number_field;
+pub mod number_locale;
 pub mod passthrough;
 pub mod password_field;
 pub mod radio_button;
 pub mod select;
 pub mod text_field;
+pub mod textarea;
+pub mod time_picker;
+#[cfg(feature = "url")]
+pub mod url_field;
+pub mod week_picker;
 
 pub type TextField = text_field::TextField;
+pub type TextArea<T> = textarea::TextArea<T>;
 pub type PasswordField = password_field::PasswordField;
+pub type EmailField<T> = email_field::EmailField<T>;
+#[cfg(feature = "url")]
+pub type UrlField = url_field::UrlField;
 pub type NumberField<T> = number_field::NumberField<T>;
-#[cfg(feature = "chrono")]
-pub type DatePicker = date_picker::DatePicker;
+pub type NumberLocale = number_locale::NumberLocale;
+#[cfg(feature = "decimal")]
+pub type DecimalField = decimal_field::DecimalField;
+pub type DatePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> = date_picker::DatePicker<T>;
+pub type TimePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveTime,
+    #[cfg(not(feature = "chrono"))] T,
+> = time_picker::TimePicker<T>;
+pub type DateTimePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDateTime,
+    #[cfg(not(feature = "chrono"))] T,
+> = datetime_picker::DateTimePicker<T>;
+pub type MonthPicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> = month_picker::MonthPicker<T>;
+pub type WeekPicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> = week_picker::WeekPicker<T>;
 pub type Checkbox = checkbox::Checkbox;
 pub type RadioButton<T> = radio_button::RadioButton<T>;
 pub type Select<T> = select::Select<T>;
 pub type MultiSelect<T> = multiselect::MultiSelect<T>;
 pub type Passthrough<T> = passthrough::Passthrough<T>;
+pub type InputHints = hints::InputHints;
+pub type Pattern = hints::Pattern;
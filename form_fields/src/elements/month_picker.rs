@@ -0,0 +1,143 @@
+use super::civil::{self, CivilDate};
+use crate::{Descriptor, validation_value::Value};
+
+/// Represents a month picker input [`<input type="month">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/month).
+/// The month is stored as its first day.
+#[derive(Debug)]
+pub struct MonthPicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// Step in months, counted from `min` or 1970-01.
+    pub step: Option<u32>,
+}
+
+impl<T: CivilDate> Descriptor for MonthPicker<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        let Some(parsed_value) = civil::parse_month(value) else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid month".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value)
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = intermediate.inner().ok_or("Value is required")?;
+        let months = civil::months(value);
+
+        if let Some(min) = &self.min
+            && months < civil::months(min)
+        {
+            return Err("Value is less than min");
+        }
+
+        if let Some(max) = &self.max
+            && months > civil::months(max)
+        {
+            return Err("Value exceeds max");
+        }
+
+        if let Some(step) = self.step {
+            let base = self.min.as_ref().map_or(1970 * 12, civil::months);
+            if (months - base) % i64::from(step.max(1)) != 0 {
+                return Err("Value doesn't match the step");
+            }
+        }
+
+        Ok(*value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        let (year, month, _) = value.ymd();
+        Value::Success(T::from_ymd(year, month, 1).unwrap_or(value))
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate as form_fields;
+    use form_fields_macro::FromForm;
+
+    #[test]
+    fn parse() {
+        let picker = MonthPicker {
+            min: None,
+            max: None,
+            step: None,
+        };
+        let mut intermediate = Value::None;
+        picker.parse("2024-02", &mut intermediate);
+        assert_eq!(
+            intermediate.inner(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).as_ref()
+        );
+
+        picker.parse("2024-13", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+    }
+
+    #[test]
+    fn validate() {
+        let picker = MonthPicker {
+            min: NaiveDate::from_ymd_opt(2024, 1, 1),
+            max: NaiveDate::from_ymd_opt(2024, 12, 1),
+            step: Some(3),
+        };
+        let month = |m| Value::Success(NaiveDate::from_ymd_opt(2024, m, 1).unwrap());
+
+        assert!(picker.validate(&month(4)).is_ok());
+        assert_eq!(
+            picker.validate(&month(5)),
+            Err("Value doesn't match the step")
+        );
+        assert_eq!(
+            picker.validate(&Value::Success(
+                NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+            )),
+            Err("Value exceeds max")
+        );
+        assert_eq!(
+            civil::format_month(&NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()),
+            "2024-04"
+        );
+    }
+
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Quarter {
+        #[month_select(display_name = "Month", min = "2024-01", step = 3)]
+        month: NaiveDate,
+    }
+
+    #[test]
+    fn derive_step() {
+        let mut form = QuarterFormSpec::new();
+        form_fields::from_form::FormSpec::parse_field(&mut form, "month", "2024-04");
+        assert_eq!(
+            form.inner().unwrap().month,
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()
+        );
+
+        let mut form = QuarterFormSpec::new();
+        form_fields::from_form::FormSpec::parse_field(&mut form, "month", "2024-02");
+        assert!(form.inner().is_none());
+        assert_eq!(
+            form.month.error.as_deref(),
+            Some("Value doesn't match the step")
+        );
+    }
+}
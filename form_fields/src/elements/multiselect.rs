@@ -1,40 +1,31 @@
 use std::{fmt::Debug, str::FromStr};
 
-use crate::{selectable::Selectable, Descriptor, FormField};
+use crate::{selectable::Selectable, Descriptor};
 
 /// Represents a multi-select input [`<input type="checkbox">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/checkbox).
 #[derive(Debug)]
 pub struct MultiSelect<T: Selectable + Debug> {
     pub options: Vec<T>,
+    pub mode: MultiSelectMode,
+}
+
+/// How a [`MultiSelect`] is rendered. Parsing and validation are the same for every mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MultiSelectMode {
+    /// A checkbox per option.
+    #[default]
+    Checkboxes,
+    /// A [`<select multiple>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select#multiple),
+    /// showing `size` options at once.
+    Select { size: Option<usize> },
+    /// A checkbox per option, styled as a switch.
+    Switches,
 }
 
 impl<T: Selectable + Debug> Descriptor for MultiSelect<T> {
     type Value = Vec<T>;
     type Intermediate = Vec<T::Key>;
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self { options } = &field.descriptor;
-        let keys = &field.intermediate;
-        maud::html! {
-            fieldset {
-                legend { (field.display_name) }
-                @for option in options {
-                    @let key = option.key();
-                    @let selected = keys.contains(&key);
-                    @let display_value = option.display_value();
-                    label {
-                        input
-                            type="checkbox"
-                            name=(field.field_name)
-                            checked[selected]
-                            value=(key.to_string()) {}
-                        (display_value)
-                    }
-                }
-            }
-        }
-    }
-
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
             return;
@@ -53,8 +44,12 @@ impl<T: Selectable + Debug> Descriptor for MultiSelect<T> {
 
         // Check if all keys are valid
         for key in keys {
-            if !options.iter().any(|option| &option.key() == key) {
-                return Err("Invalid option selected");
+            let option = options
+                .iter()
+                .find(|option| &option.key() == key)
+                .ok_or("Invalid option selected")?;
+            if option.disabled() {
+                return Err("Option is not available");
             }
         }
 
@@ -79,6 +74,7 @@ mod test {
     fn parse() {
         let multiselect = MultiSelect::<String> {
             options: vec![],
+            mode: MultiSelectMode::Checkboxes,
         };
         let mut intermediate = vec![];
         multiselect.parse("option1", &mut intermediate);
@@ -92,7 +88,10 @@ mod test {
     #[test]
     fn validate() {
         let options = vec!["option1".to_string(), "option2".to_string()];
-        let multiselect = MultiSelect { options };
+        let multiselect = MultiSelect {
+            options,
+            mode: MultiSelectMode::default(),
+        };
 
         // Valid selection
         let keys = vec!["option1".to_string(), "option2".to_string()];
@@ -113,4 +112,77 @@ mod test {
         let keys: Vec<String> = vec![];
         assert_eq!(multiselect.validate(&keys), Ok(vec![]));
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_modes() {
+        use crate::{FormField, render::maud::Maud, render::theme::Theme};
+
+        let mut field = FormField {
+            intermediate: vec![1, 3],
+            ..FormField::new(
+                "Models",
+                "models",
+                MultiSelect {
+                    options: vec![
+                        (1, "Golf".to_string()),
+                        (2, "Model 3".to_string()),
+                        (3, "Polo".to_string()),
+                    ],
+                    mode: MultiSelectMode::Select { size: Some(4) },
+                },
+            )
+        };
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"models\">Models</label>\
+            <select id=\"models\" name=\"models\" multiple size=\"4\">\
+            <option value=\"1\" selected>Golf</option>\
+            <option value=\"2\">Model 3</option>\
+            <option value=\"3\" selected>Polo</option>\
+            </select>"
+        );
+
+        field.descriptor.options.truncate(1);
+        field.descriptor.mode = MultiSelectMode::Switches;
+        field.theme = Some(&Theme::BOOTSTRAP5);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<fieldset class=\"mb-3\"><legend class=\"form-label\">Models</legend>\
+            <div class=\"form-check form-switch\"><label class=\"form-check-label\">\
+            <input type=\"checkbox\" role=\"switch\" id=\"models\" class=\"form-check-input\" \
+            name=\"models\" checked value=\"1\"></input>Golf</label></div>\
+            </fieldset>"
+        );
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_bulma_select() {
+        use crate::{
+            FormField,
+            render::maud::{Maud, RenderParts},
+            render::theme::Theme,
+        };
+
+        let field = FormField {
+            error: Some("Invalid option selected".to_string()),
+            theme: Some(&Theme::BULMA),
+            ..FormField::new(
+                "Sizes",
+                "sizes",
+                MultiSelect {
+                    options: vec![1u8, 2],
+                    mode: MultiSelectMode::Select { size: None },
+                },
+            )
+        };
+        assert_eq!(
+            Maud::control(&field).into_string(),
+            "<div class=\"control\"><div class=\"select is-danger is-multiple\">\
+            <select id=\"sizes\" name=\"sizes\" multiple aria-invalid=\"true\" aria-describedby=\"sizes-error\">\
+            <option value=\"1\">1</option><option value=\"2\">2</option>\
+            </select></div></div>"
+        );
+    }
+}
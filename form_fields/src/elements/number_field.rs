@@ -1,41 +1,141 @@
 use std::{fmt::Display, str::FromStr};
 
-use crate::{Descriptor, FormField, validation_value::Value};
+use super::{hints::InputHints, number_locale::NumberLocale};
+use crate::{Descriptor, validation_value::Value};
 
 /// Represents a number input field [`<input type="number">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/number).
 #[derive(Debug)]
 pub struct NumberField<T: PartialOrd> {
     pub min: Option<T>,
     pub max: Option<T>,
+    /// Step, counted from `min` or zero. Without a step, the browser only accepts integers.
+    pub step: Option<Step<T>>,
+    /// Accepts and renders the separators of the locale, in a text input.
+    pub locale: Option<NumberLocale>,
+    pub hints: InputHints,
 }
 
-impl<T: PartialOrd + maud::Render + Display + Copy + FromStr> Descriptor for NumberField<T> {
-    type Value = T;
-    type Intermediate = Value<T>;
+/// The `step` attribute of a number input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step<T> {
+    /// Any value is accepted.
+    Any,
+    Value(T),
+}
+
+/// Numbers usable in a [`NumberField`].
+/// Implemented for the primitive numbers, and can be implemented for other types, e.g. newtypes.
+pub trait Number: PartialOrd + Display + Copy + FromStr {
+    const ZERO: Self;
+    /// Whether the type only holds integers.
+    const INTEGER: bool;
+
+    /// Checks if the value is a whole number of steps away from `base`.
+    fn is_step_aligned(self, base: Self, step: Self) -> bool;
+}
+
+macro_rules! impl_number_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const INTEGER: bool = true;
+
+                fn is_step_aligned(self, base: Self, step: Self) -> bool {
+                    // Unsigned distances can't overflow, even between `MIN` and `MAX`.
+                    step == 0 || self.abs_diff(base) % step.unsigned_abs() == 0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_number_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const INTEGER: bool = true;
+
+                fn is_step_aligned(self, base: Self, step: Self) -> bool {
+                    step == 0 || self.abs_diff(base) % step == 0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_number_float {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0.0;
+                const INTEGER: bool = false;
+
+                fn is_step_aligned(self, base: Self, step: Self) -> bool {
+                    if step == 0.0 {
+                        return true;
+                    }
+                    // Allow for rounding errors, e.g. 0.3 isn't exactly 3 * 0.1.
+                    let steps = (self - base) / step;
+                    (steps - steps.round()).abs() <= <$t>::EPSILON * 4.0 * steps.abs().max(1.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_integer!(i8, i16, i32, i64, i128, isize);
+impl_number_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_number_float!(f32, f64);
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self { min, max } = &field.descriptor;
-        let value = field.intermediate.map(|v| v.to_string());
-
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            input
-                type="number"
-                name=(field.field_name)
-                value=[value]
-                min=[min]
-                max=[max]
-                required[field.required] {}
+impl<T: Number> NumberField<T> {
+    /// The `step` attribute of the input.
+    /// Floats without a step accept any value, integers use the browser default.
+    pub fn step_attribute(&self) -> Option<String> {
+        match self.step {
+            Some(Step::Any) => Some("any".to_string()),
+            Some(Step::Value(step)) => Some(step.to_string()),
+            None if !T::INTEGER => Some("any".to_string()),
+            None => None,
         }
     }
 
+    /// The `inputmode` of the input. Localized fields are text inputs,
+    /// so they request a numeric keyboard unless the hints set one.
+    pub fn inputmode(&self) -> Option<&str> {
+        match (&self.hints.inputmode, &self.locale) {
+            (Some(inputmode), _) => Some(inputmode),
+            (None, Some(_)) if T::INTEGER => Some("numeric"),
+            (None, Some(_)) => Some("decimal"),
+            (None, None) => None,
+        }
+    }
+
+    /// Formats the value with the separators of the locale, if any.
+    pub fn format(&self, value: &T) -> String {
+        match &self.locale {
+            Some(locale) => locale.format(&value.to_string()),
+            None => value.to_string(),
+        }
+    }
+}
+
+impl<T: Number> Descriptor for NumberField<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
+
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
             *intermediate = Value::None;
             return;
         }
 
-        let Ok(parsed_value) = T::from_str(value) else {
+        let parsed_value = match &self.locale {
+            Some(locale) => locale.normalize(value).and_then(|value| T::from_str(&value).ok()),
+            None => T::from_str(value).ok(),
+        };
+        let Some(parsed_value) = parsed_value else {
             *intermediate = Value::Failure(value.to_string(), "Invalid number".to_string());
             return;
         };
@@ -44,6 +144,9 @@ impl<T: PartialOrd + maud::Render + Display + Copy + FromStr> Descriptor for Num
     }
 
     fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        if let Value::Failure(_, _) = intermediate {
+            return Err("Invalid number");
+        }
         let value = intermediate.inner().ok_or("Value is required")?;
 
         if let Some(min) = self.min
@@ -58,6 +161,13 @@ impl<T: PartialOrd + maud::Render + Display + Copy + FromStr> Descriptor for Num
             return Err("Value exceeds max");
         }
 
+        if let Some(Step::Value(step)) = self.step {
+            let base = self.min.unwrap_or(T::ZERO);
+            if !value.is_step_aligned(base, step) {
+                return Err("Value doesn't match the step");
+            }
+        }
+
         Ok(*value)
     }
 
@@ -75,6 +185,9 @@ mod test {
         let number_field = NumberField {
             min: Some(10),
             max: Some(100),
+            step: None,
+            locale: None,
+            hints: InputHints::default(),
         };
         let mut intermediate = Value::None;
 
@@ -93,6 +206,9 @@ mod test {
         let number_field = NumberField {
             min: Some(10),
             max: Some(100),
+            step: None,
+            locale: None,
+            hints: InputHints::default(),
         };
 
         assert_eq!(number_field.validate(&Value::Success(10)), Ok(10));
@@ -111,4 +227,90 @@ mod test {
             Err("Value is required")
         );
     }
+
+    #[test]
+    fn step() {
+        let number_field = NumberField {
+            min: Some(1),
+            max: None,
+            step: Some(Step::Value(5)),
+            locale: None,
+            hints: InputHints::default(),
+        };
+        assert_eq!(number_field.validate(&Value::Success(11)), Ok(11));
+        assert_eq!(
+            number_field.validate(&Value::Success(10)),
+            Err("Value doesn't match the step")
+        );
+        assert_eq!(number_field.step_attribute().as_deref(), Some("5"));
+
+        let number_field = NumberField {
+            min: Some(i128::MIN),
+            max: None,
+            step: Some(Step::Value(2)),
+            locale: None,
+            hints: InputHints::default(),
+        };
+        assert_eq!(
+            number_field.validate(&Value::Success(i128::MAX)),
+            Err("Value doesn't match the step")
+        );
+        assert_eq!(number_field.validate(&Value::Success(0)), Ok(0));
+
+        let number_field = NumberField {
+            min: None,
+            max: None,
+            step: Some(Step::Value(u128::MAX)),
+            locale: None,
+            hints: InputHints::default(),
+        };
+        assert_eq!(number_field.validate(&Value::Success(u128::MAX)), Ok(u128::MAX));
+
+        let number_field = NumberField {
+            min: None,
+            max: None,
+            step: Some(Step::Value(0.1)),
+            locale: None,
+            hints: InputHints::default(),
+        };
+        assert_eq!(number_field.validate(&Value::Success(0.3)), Ok(0.3));
+        assert_eq!(number_field.validate(&Value::Success(-2.7)), Ok(-2.7));
+        assert_eq!(
+            number_field.validate(&Value::Success(0.35)),
+            Err("Value doesn't match the step")
+        );
+
+        let number_field = NumberField::<f32> {
+            min: None,
+            max: None,
+            step: None,
+            locale: None,
+            hints: InputHints::default(),
+        };
+        assert_eq!(number_field.validate(&Value::Success(0.35)), Ok(0.35));
+        assert_eq!(number_field.step_attribute().as_deref(), Some("any"));
+    }
+
+    #[test]
+    fn locale() {
+        let number_field = NumberField {
+            min: None,
+            max: None,
+            step: None,
+            locale: Some(NumberLocale::DE),
+            hints: InputHints::default(),
+        };
+        let mut intermediate = Value::None;
+
+        number_field.parse("1.234,5", &mut intermediate);
+        assert_eq!(intermediate.inner(), Some(&1234.5));
+        assert_eq!(number_field.format(&1234.5), "1.234,5");
+
+        number_field.parse("1,234.5", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+        assert_eq!(number_field.validate(&intermediate), Err("Invalid number"));
+
+        let intermediate = number_field.load(1234.5);
+        assert_eq!(number_field.validate(&intermediate), Ok(1234.5));
+    }
 }
@@ -0,0 +1,143 @@
+/// Decimal and grouping separators of a locale, for number fields that accept
+/// input like `1.234,5` instead of `1234.5`.
+///
+/// A localized field is rendered as a text input, since `<input type="number">`
+/// only handles the browser's own locale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NumberLocale {
+    pub decimal: char,
+    /// Accepted grouping separators. The first one is used for rendering.
+    pub grouping: &'static [char],
+}
+
+impl NumberLocale {
+    /// `1,234.5`
+    pub const EN: NumberLocale = NumberLocale {
+        decimal: '.',
+        grouping: &[','],
+    };
+    /// `1.234,5`
+    pub const DE: NumberLocale = NumberLocale {
+        decimal: ',',
+        grouping: &['.'],
+    };
+    /// `1 234,5`, with a narrow no-break space, a no-break space or a plain space.
+    pub const FR: NumberLocale = NumberLocale {
+        decimal: ',',
+        grouping: &['\u{202f}', '\u{a0}', ' '],
+    };
+    /// `1'234.5`
+    pub const CH: NumberLocale = NumberLocale {
+        decimal: '.',
+        grouping: &['\'', '\u{2019}'],
+    };
+
+    /// Converts localized input into the format understood by `FromStr`.
+    /// Returns `None` if the separators are misplaced, e.g. `1.23,4` or `1,` in German.
+    pub fn normalize(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        let (sign, value) = match value.strip_prefix('-') {
+            Some(value) => ("-", value),
+            None => ("", value),
+        };
+        let (integer, fraction) = match value.split_once(self.decimal) {
+            // A separator needs digits after it, e.g. `1,` isn't a German number.
+            Some((_, "")) => return None,
+            Some(parts) => parts,
+            None => (value, ""),
+        };
+        if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut groups = integer.split(|c| self.grouping.contains(&c));
+        let first = groups.next().unwrap_or_default();
+        let mut digits = first.to_string();
+        let mut grouped = false;
+        for group in groups {
+            if group.len() != 3 {
+                return None;
+            }
+            grouped = true;
+            digits.push_str(group);
+        }
+        if first.is_empty()
+            || (grouped && first.len() > 3)
+            || !digits.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        if fraction.is_empty() {
+            Some(format!("{sign}{digits}"))
+        } else {
+            Some(format!("{sign}{digits}.{fraction}"))
+        }
+    }
+
+    /// Formats a number written like `-1234.5` with the separators of the locale.
+    /// Other formats, like exponents, are returned unchanged.
+    pub fn format(&self, value: &str) -> String {
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(value) => ("-", value),
+            None => ("", value),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer.is_empty()
+            || !(integer.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit())
+        {
+            return value.to_string();
+        }
+
+        let mut formatted = sign.to_string();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                formatted.extend(self.grouping.first());
+            }
+            formatted.push(digit);
+        }
+        if !fraction.is_empty() {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        let de = NumberLocale::DE;
+        assert_eq!(de.normalize("1.234,5").as_deref(), Some("1234.5"));
+        assert_eq!(de.normalize("-1234,50").as_deref(), Some("-1234.50"));
+        assert_eq!(de.normalize("1.234").as_deref(), Some("1234"));
+        assert_eq!(de.normalize("1,234.50"), None);
+        assert_eq!(de.normalize("12.34"), None);
+        assert_eq!(de.normalize("1234.567"), None);
+        assert_eq!(de.normalize("1,"), None);
+        assert_eq!(de.normalize("-1.234,"), None);
+
+        let en = NumberLocale::EN;
+        assert_eq!(en.normalize("1,234.50").as_deref(), Some("1234.50"));
+        assert_eq!(en.normalize(" 12 ").as_deref(), Some("12"));
+        assert_eq!(en.normalize("1,5"), None);
+        assert_eq!(en.normalize(".5"), None);
+
+        let fr = NumberLocale::FR;
+        assert_eq!(fr.normalize("1\u{202f}234,5").as_deref(), Some("1234.5"));
+        assert_eq!(fr.normalize("1 234 567").as_deref(), Some("1234567"));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(NumberLocale::DE.format("-1234567.25"), "-1.234.567,25");
+        assert_eq!(NumberLocale::DE.format("123"), "123");
+        assert_eq!(NumberLocale::CH.format("1234"), "1'234");
+        assert_eq!(NumberLocale::EN.format("1e21"), "1e21");
+        assert_eq!(NumberLocale::EN.format("NaN"), "NaN");
+    }
+}
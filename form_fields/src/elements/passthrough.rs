@@ -1,8 +1,9 @@
 
-use crate::{Descriptor, FormField};
+use crate::Descriptor;
 
 /// Doesn't represent any specific HTML input type. Instead, it simply passes the value through as-is.
 /// This is useful for custom or complex types that don't fit into standard HTML input types.
+/// Rendered as hidden inputs.
 #[derive(Debug, Default)]
 pub struct Passthrough<T> {
     _marker: std::marker::PhantomData<T>,
@@ -12,9 +13,7 @@ impl Descriptor for Passthrough<String> {
     type Value = String;
     type Intermediate = Option<String>;
 
-    fn render(_field: &FormField<Self>) -> maud::Markup {
-        panic!("Passthrough cannot be rendered directly");
-    }
+    const HIDDEN: bool = true;
 
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
@@ -40,9 +39,7 @@ impl Descriptor for Passthrough<Vec<String>> {
     type Value = Vec<String>;
     type Intermediate = Vec<String>;
 
-    fn render(_field: &FormField<Self>) -> maud::Markup {
-        panic!("Passthrough cannot be rendered directly");
-    }
+    const HIDDEN: bool = true;
 
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
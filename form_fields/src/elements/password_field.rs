@@ -1,30 +1,19 @@
-use crate::{Descriptor, FormField};
+use super::hints::{InputHints, Pattern};
+use crate::Descriptor;
 
 /// Represents a password input field [`<input type="password">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/password).
 #[derive(Debug)]
 pub struct PasswordField {
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub pattern: Option<Pattern>,
+    pub hints: InputHints,
 }
 
 impl Descriptor for PasswordField {
     type Value = String;
     type Intermediate = Option<String>;
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let self_ = &field.descriptor;
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            input
-                type="password"
-                name=(field.field_name)
-                value=[field.intermediate.as_deref()]
-                minlength=[self_.min_length]
-                maxlength=[self_.max_length]
-                required[field.required] {}
-        }
-    }
-
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
             *intermediate = None;
@@ -49,6 +38,13 @@ impl Descriptor for PasswordField {
             return Err("Value exceeds max length");
         }
 
+        #[cfg(feature = "regex")]
+        if let Some(pattern) = &self.pattern
+            && !pattern.is_match(value)
+        {
+            return Err("Value does not match the required format");
+        }
+
         Ok(value.clone())
     }
 
@@ -60,12 +56,18 @@ impl Descriptor for PasswordField {
 #[cfg(test)]
 mod test {
     use super::*;
+    #[cfg(feature = "maud")]
+    use crate::{self as form_fields, render::maud::Maud};
+    #[cfg(feature = "maud")]
+    use form_fields_macro::FromForm;
 
     #[test]
     fn parse() {
         let descriptor = PasswordField {
             min_length: None,
             max_length: None,
+            pattern: None,
+            hints: InputHints::default(),
         };
         let mut intermediate = None;
         descriptor.parse("Hello", &mut intermediate);
@@ -73,10 +75,13 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn validate() {
         let descriptor = PasswordField {
             min_length: Some(3),
             max_length: Some(10),
+            pattern: None,
+            hints: InputHints::default(),
         };
 
         let mut intermediate = Some("Hello".to_string());
@@ -91,4 +96,30 @@ mod test {
         intermediate = None;
         assert!(matches!(descriptor.validate(&intermediate), Err(_)));
     }
+
+    #[cfg(feature = "maud")]
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Login {
+        #[password_field(
+            display_name = "Password",
+            autocomplete = "current-password",
+            spellcheck = false,
+            size = 20,
+            readonly
+        )]
+        password: String,
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_hints() {
+        let form = LoginFormSpec::new();
+        assert_eq!(
+            form.password.render_with::<Maud>().into_string(),
+            "<label for=\"login-password\">Password</label>\
+            <input type=\"password\" id=\"login-password\" name=\"password\" \
+            autocomplete=\"current-password\" spellcheck=\"false\" size=\"20\" readonly required></input>"
+        );
+    }
 }
@@ -1,11 +1,12 @@
 use std::{fmt::Debug, str::FromStr};
 
-use crate::{Descriptor, FormField, selectable::Selectable};
+use crate::{Descriptor, selectable::Selectable};
 
 /// Represents a radio button input [`<input type="radio">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/radio).
 #[derive(Debug)]
 pub struct RadioButton<T: Selectable + Debug> {
     pub options: Vec<T>,
+    /// Checked until another option is chosen, unless it is disabled.
     pub default_value: T,
 }
 
@@ -13,31 +14,6 @@ impl<T: Selectable + Debug> Descriptor for RadioButton<T> {
     type Value = T;
     type Intermediate = Option<T::Key>;
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self {
-            options,
-            default_value,
-        } = &field.descriptor;
-        let default = default_value.key();
-        let selected = field.intermediate.as_ref().unwrap_or(&default);
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            @for option in options {
-                @let key = option.key();
-                @let display_value = option.display_value();
-                label {
-                    input
-                        type="radio"
-                        name=(field.field_name)
-                        value=(key.to_string())
-                        checked[selected == &key]
-                        required[field.required] {}
-                    (display_value)
-                }
-            }
-        }
-    }
-
     fn parse(&self, value: &str, key: &mut Self::Intermediate) {
         if value.is_empty() {
             *key = None;
@@ -54,11 +30,14 @@ impl<T: Selectable + Debug> Descriptor for RadioButton<T> {
         // Check if the key is valid
         let options = &self.options;
 
-        options
+        let option = options
             .iter()
             .find(|&kv| &kv.key() == key)
-            .cloned()
-            .ok_or("Invalid value")
+            .ok_or("Invalid value")?;
+        if option.disabled() {
+            return Err("Option is not available");
+        }
+        Ok(option.clone())
     }
 
     fn load(&self, value: Self::Value) -> Self::Intermediate {
@@ -104,4 +83,33 @@ mod test {
         intermediate = Some("invalid_option".to_string());
         assert!(radio.validate(&intermediate).is_err());
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render() {
+        use crate::{FormField, render::maud::Maud};
+
+        let field = FormField {
+            id: "order-size",
+            error: Some("Invalid value".to_string()),
+            autofocus: true,
+            ..FormField::new(
+                "Size",
+                "size",
+                RadioButton {
+                    options: vec![1u8, 2],
+                    default_value: 1,
+                },
+            )
+        };
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<fieldset><legend>Size</legend>\
+            <label><input type=\"radio\" id=\"order-size\" name=\"size\" value=\"1\" checked autofocus \
+            aria-invalid=\"true\" aria-describedby=\"order-size-error\"></input>1</label>\
+            <label><input type=\"radio\" id=\"order-size-1\" name=\"size\" value=\"2\" \
+            aria-invalid=\"true\" aria-describedby=\"order-size-error\"></input>2</label>\
+            </fieldset>"
+        );
+    }
+}
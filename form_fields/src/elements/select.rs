@@ -1,6 +1,6 @@
 use std::{fmt::Debug, str::FromStr};
 
-use crate::{Descriptor, FormField, selectable::Selectable};
+use crate::{Descriptor, selectable::Selectable};
 
 /// Represents a select input [`<select>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select).
 #[derive(Debug)]
@@ -14,30 +14,6 @@ impl<T: Selectable + Debug> Descriptor for Select<T> {
     type Value = T;
     type Intermediate = Option<T::Key>;
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let Self {
-            default_value,
-            options,
-            placeholder,
-        } = &field.descriptor;
-        let default = default_value.as_ref().map(|v| v.key());
-        let selected = field.intermediate.as_ref().or(default.as_ref());
-        let has_value = field.intermediate.is_some();
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            select name=(field.field_name) required[field.required] {
-                option value="" disabled[field.required] selected[!has_value] { (placeholder) }
-                @for option in options {
-                    @let key = option.key();
-                    @let display_value = option.display_value();
-                    option
-                        value=(key.to_string())
-                        selected[selected == Some(&key)] { (display_value) }
-                }
-            }
-        }
-    }
-
     fn parse(&self, value: &str, key: &mut Self::Intermediate) {
         if value.is_empty() {
             *key = None;
@@ -54,11 +30,14 @@ impl<T: Selectable + Debug> Descriptor for Select<T> {
         // Check if the key is valid
         let options = &self.options;
 
-        options
+        let option = options
             .iter()
             .find(|&option| &option.key() == key)
-            .cloned()
-            .ok_or("Invalid value")
+            .ok_or("Invalid value")?;
+        if option.disabled() {
+            return Err("Option is not available");
+        }
+        Ok(option.clone())
     }
 
     fn load(&self, value: Self::Value) -> Self::Intermediate {
@@ -106,4 +85,98 @@ mod test {
         intermediate = Some("invalid_option".to_string());
         assert!(radio.validate(&intermediate).is_err());
     }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render() {
+        use crate::{FormField, render::maud::Maud};
+
+        let field = FormField {
+            intermediate: Some(2),
+            ..FormField::new(
+                "Pick",
+                "pick",
+                Select {
+                    default_value: None,
+                    options: vec![1u8, 2],
+                    placeholder: "--".to_string(),
+                },
+            )
+        };
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"pick\">Pick</label>\
+            <select id=\"pick\" name=\"pick\">\
+            <option value=\"\">--</option>\
+            <option value=\"1\">1</option>\
+            <option value=\"2\" selected>2</option>\
+            </select>"
+        );
+    }
+
+    #[cfg(feature = "maud")]
+    #[derive(Debug, Clone)]
+    struct Model {
+        id: u32,
+        name: &'static str,
+        manufacturer: Option<&'static str>,
+    }
+
+    #[cfg(feature = "maud")]
+    impl Selectable for Model {
+        type Key = u32;
+        type DisplayValue = &'static str;
+
+        fn key(&self) -> u32 {
+            self.id
+        }
+
+        fn display_value(&self) -> &'static str {
+            self.name
+        }
+
+        fn group(&self) -> Option<String> {
+            self.manufacturer.map(str::to_string)
+        }
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_groups() {
+        use crate::{FormField, render::maud::Maud};
+
+        let model = |id, name, manufacturer| Model {
+            id,
+            name,
+            manufacturer,
+        };
+        let field = FormField {
+            intermediate: Some(3),
+            ..FormField::new(
+                "Model",
+                "model",
+                Select {
+                    default_value: None,
+                    options: vec![
+                        model(1, "Golf", Some("VW")),
+                        model(2, "Model 3", Some("Tesla")),
+                        model(3, "Polo", Some("VW")),
+                        model(4, "Other", None),
+                    ],
+                    placeholder: "--".to_string(),
+                },
+            )
+        };
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"model\">Model</label>\
+            <select id=\"model\" name=\"model\">\
+            <option value=\"\">--</option>\
+            <optgroup label=\"VW\"><option value=\"1\">Golf</option><option value=\"3\" selected>Polo</option></optgroup>\
+            <optgroup label=\"Tesla\"><option value=\"2\">Model 3</option></optgroup>\
+            <option value=\"4\">Other</option>\
+            </select>"
+        );
+        assert_eq!(field.descriptor.validate(&Some(2)).unwrap().name, "Model 3");
+    }
 }
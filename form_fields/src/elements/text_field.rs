@@ -1,32 +1,25 @@
-use crate::{Descriptor, FormField};
+use super::hints::{InputHints, Pattern};
+use crate::Descriptor;
 
 /// Represents a text input field [`<input type="text">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/text).
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct TextField {
     pub placeholder: Option<String>,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub pattern: Option<Pattern>,
+    pub hints: InputHints,
+    /// Values offered in a linked [`<datalist>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/datalist).
+    /// Can be replaced at runtime, e.g. with values loaded from a database.
+    pub suggestions: Vec<String>,
+    /// Only accept values from `suggestions`.
+    pub restrict_to_suggestions: bool,
 }
 
 impl Descriptor for TextField {
     type Value = String;
     type Intermediate = Option<String>;
 
-    fn render(field: &FormField<Self>) -> maud::Markup {
-        let self_ = &field.descriptor;
-        maud::html! {
-            label for=(field.field_name) { (field.display_name) }
-            input
-                type="text"
-                name=(field.field_name)
-                value=[field.intermediate.as_deref()]
-                placeholder=[self_.placeholder.as_deref()]
-                minlength=[self_.min_length]
-                maxlength=[self_.max_length]
-                required[field.required] {}
-        }
-    }
-
     fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
         if value.is_empty() {
             *intermediate = None;
@@ -51,6 +44,17 @@ impl Descriptor for TextField {
             return Err("Value exceeds max length");
         }
 
+        #[cfg(feature = "regex")]
+        if let Some(pattern) = &self.pattern
+            && !pattern.is_match(value)
+        {
+            return Err("Value does not match the required format");
+        }
+
+        if self.restrict_to_suggestions && !self.suggestions.contains(value) {
+            return Err("Value is not one of the suggestions");
+        }
+
         Ok(value.clone())
     }
 
@@ -62,6 +66,10 @@ impl Descriptor for TextField {
 #[cfg(test)]
 mod test {
     use super::*;
+    #[cfg(feature = "maud")]
+    use crate::{self as form_fields, FormField, render::maud::Maud};
+    #[cfg(feature = "maud")]
+    use form_fields_macro::FromForm;
 
     #[test]
     fn parse() {
@@ -69,6 +77,10 @@ mod test {
             placeholder: None,
             min_length: None,
             max_length: None,
+            pattern: None,
+            hints: InputHints::default(),
+            suggestions: Vec::new(),
+            restrict_to_suggestions: false,
         };
         let mut intermediate = None;
         descriptor.parse("Hello", &mut intermediate);
@@ -76,11 +88,16 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn validate() {
         let descriptor = TextField {
             placeholder: None,
             min_length: Some(3),
             max_length: Some(10),
+            pattern: None,
+            hints: InputHints::default(),
+            suggestions: Vec::new(),
+            restrict_to_suggestions: false,
         };
 
         let mut intermediate = Some("Hello".to_string());
@@ -95,4 +112,137 @@ mod test {
         intermediate = None;
         assert!(matches!(descriptor.validate(&intermediate), Err(_)));
     }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn validate_pattern() {
+        let descriptor = TextField {
+            placeholder: None,
+            min_length: None,
+            max_length: None,
+            pattern: Some(Pattern::new("[0-9]{5}").unwrap()),
+            hints: InputHints::default(),
+            suggestions: Vec::new(),
+            restrict_to_suggestions: false,
+        };
+
+        assert!(descriptor.validate(&Some("12345".to_string())).is_ok());
+        assert!(descriptor.validate(&Some("1234".to_string())).is_err());
+        assert!(descriptor.validate(&Some("123456".to_string())).is_err());
+    }
+
+    #[test]
+    fn validate_suggestions() {
+        let mut descriptor = TextField {
+            placeholder: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            hints: InputHints::default(),
+            suggestions: vec!["Berlin".to_string(), "Zurich".to_string()],
+            restrict_to_suggestions: false,
+        };
+
+        assert!(descriptor.validate(&Some("Paris".to_string())).is_ok());
+
+        descriptor.restrict_to_suggestions = true;
+        assert!(descriptor.validate(&Some("Zurich".to_string())).is_ok());
+        assert_eq!(
+            descriptor.validate(&Some("Paris".to_string())),
+            Err("Value is not one of the suggestions")
+        );
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render() {
+        let field = FormField {
+            id: "person-name",
+            intermediate: Some("<b>".to_string()),
+            required: true,
+            error: Some("Value exceeds max length".to_string()),
+            help_text: Some("Your name"),
+            ..FormField::new(
+                "Name",
+                "name",
+                TextField {
+                    max_length: Some(10),
+                    ..Default::default()
+                },
+            )
+        };
+        assert_eq!(
+            maud::Render::render(&field).into_string(),
+            "<div class=\"form-group\">\
+            <label for=\"person-name\">Name</label>\
+            <input type=\"text\" id=\"person-name\" name=\"name\" value=\"&lt;b&gt;\" maxlength=\"10\" required \
+            aria-invalid=\"true\" aria-describedby=\"person-name-help person-name-error\"></input>\
+            <div id=\"person-name-help\" class=\"help-text\">Your name</div>\
+            <div id=\"person-name-error\" class=\"error\">Value exceeds max length</div>\
+            </div>"
+        );
+    }
+
+    #[cfg(all(feature = "maud", feature = "regex"))]
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Verification {
+        #[text_field(
+            display_name = "Code",
+            pattern = "[0-9]{6}",
+            inputmode = "numeric",
+            autocomplete = "one-time-code"
+        )]
+        code: String,
+    }
+
+    #[cfg(all(feature = "maud", feature = "regex"))]
+    #[test]
+    fn render_hints() {
+        let form = VerificationFormSpec::new();
+        assert_eq!(
+            form.code.render_with::<Maud>().into_string(),
+            "<label for=\"verification-code\">Code</label>\
+            <input type=\"text\" id=\"verification-code\" name=\"code\" pattern=\"[0-9]{6}\" \
+            autocomplete=\"one-time-code\" inputmode=\"numeric\" required></input>"
+        );
+    }
+
+    #[cfg(feature = "maud")]
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Relocation {
+        #[text_field(display_name = "City", suggestions = ["Berlin", "Zurich"], restrict_to_suggestions)]
+        city: String,
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_suggestions() {
+        let mut form = RelocationFormSpec::new();
+        form_fields::from_form::FormSpec::parse_field(&mut form, "city", "Zurich");
+        assert_eq!(
+            form.city.render_with::<Maud>().into_string(),
+            "<label for=\"relocation-city\">City</label>\
+            <input type=\"text\" id=\"relocation-city\" name=\"city\" value=\"Zurich\" \
+            list=\"relocation-city-suggestions\" required></input>\
+            <datalist id=\"relocation-city-suggestions\">\
+            <option value=\"Berlin\"></option><option value=\"Zurich\"></option>\
+            </datalist>"
+        );
+        assert_eq!(form.inner().unwrap().city, "Zurich");
+
+        let mut form = RelocationFormSpec::new();
+        form.city.descriptor.suggestions.push("Paris".to_string());
+        form_fields::from_form::FormSpec::parse_field(&mut form, "city", "Paris");
+        assert_eq!(form.inner().unwrap().city, "Paris");
+
+        let mut form = RelocationFormSpec::new();
+        form_fields::from_form::FormSpec::parse_field(&mut form, "city", "Paris");
+        assert!(form.inner().is_none());
+        assert_eq!(
+            form.city.error.as_deref(),
+            Some("Value is not one of the suggestions")
+        );
+    }
 }
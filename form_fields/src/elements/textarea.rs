@@ -0,0 +1,156 @@
+use crate::Descriptor;
+
+/// Represents a multi-line text input [`<textarea>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea).
+/// Line endings are normalized to `\n`. Input with only whitespace counts as empty.
+/// `TextArea<Vec<String>>` returns every non-empty line, e.g. for one tag per line.
+#[derive(Debug)]
+pub struct TextArea<T> {
+    pub placeholder: Option<String>,
+    pub rows: Option<usize>,
+    pub cols: Option<usize>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    /// `soft`, `hard` or `off`.
+    pub wrap: Option<String>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> Default for TextArea<T> {
+    fn default() -> Self {
+        Self {
+            placeholder: None,
+            rows: None,
+            cols: None,
+            min_length: None,
+            max_length: None,
+            wrap: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> TextArea<T> {
+    fn parse_text(&self, value: &str, intermediate: &mut Option<String>) {
+        if value.trim().is_empty() {
+            *intermediate = None;
+            return;
+        }
+
+        *intermediate = Some(value.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+
+    fn validate_text<'a>(&self, intermediate: &'a Option<String>) -> Result<&'a String, &'_ str> {
+        let value = intermediate.as_ref().ok_or("Value is required")?;
+
+        if let Some(min_length) = self.min_length
+            && value.len() < min_length
+        {
+            return Err("Value is shorter than min length");
+        }
+
+        if let Some(max_length) = self.max_length
+            && value.len() > max_length
+        {
+            return Err("Value exceeds max length");
+        }
+
+        Ok(value)
+    }
+}
+
+impl Descriptor for TextArea<String> {
+    type Value = String;
+    type Intermediate = Option<String>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        self.parse_text(value, intermediate);
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        self.validate_text(intermediate).cloned()
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Some(value)
+    }
+}
+
+impl Descriptor for TextArea<Vec<String>> {
+    type Value = Vec<String>;
+    type Intermediate = Option<String>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        self.parse_text(value, intermediate);
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let lines: Vec<String> = self
+            .validate_text(intermediate)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        if lines.is_empty() {
+            return Err("Value is required");
+        }
+
+        Ok(lines)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Some(value.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        let descriptor = TextArea::<String>::default();
+        let mut intermediate = None;
+        descriptor.parse("one\r\ntwo\rthree\n", &mut intermediate);
+        assert_eq!(intermediate.as_deref(), Some("one\ntwo\nthree\n"));
+        assert_eq!(
+            descriptor.validate(&intermediate),
+            Ok("one\ntwo\nthree\n".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_lines() {
+        let descriptor = TextArea::<Vec<String>> {
+            max_length: Some(20),
+            ..Default::default()
+        };
+        let mut intermediate = None;
+        descriptor.parse("rust\r\n\r\n  web \r\n", &mut intermediate);
+        assert_eq!(
+            descriptor.validate(&intermediate),
+            Ok(vec!["rust".to_string(), "web".to_string()])
+        );
+
+        // Blank lines only leave an optional field empty instead of failing it.
+        descriptor.parse("\r\n \r\n", &mut intermediate);
+        assert_eq!(intermediate, None);
+        assert!(!crate::Intermediate::has_value(&intermediate));
+        assert!(descriptor.validate(&intermediate).is_err());
+
+        descriptor.parse("a very long list of tags", &mut intermediate);
+        assert!(descriptor.validate(&intermediate).is_err());
+    }
+
+    #[test]
+    fn load() {
+        let descriptor = TextArea::<Vec<String>>::default();
+        let intermediate = descriptor.load(vec!["rust".to_string(), "web".to_string()]);
+        assert_eq!(intermediate.as_deref(), Some("rust\nweb"));
+        assert_eq!(
+            descriptor.validate(&intermediate),
+            Ok(vec!["rust".to_string(), "web".to_string()])
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use super::civil::{self, CivilTime};
+use crate::{Descriptor, validation_value::Value};
+
+/// Represents a time picker input [`<input type="time">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/time).
+/// Accepts values with and without seconds.
+#[derive(Debug)]
+pub struct TimePicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveTime,
+    #[cfg(not(feature = "chrono"))] T,
+> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// Step in seconds, counted from `min` or midnight. The browser defaults to 60.
+    pub step: Option<u32>,
+}
+
+impl<T: CivilTime> Descriptor for TimePicker<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        let Some(parsed_value) = civil::parse_time(value) else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid time".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value)
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = intermediate.inner().ok_or("Value is required")?;
+
+        if let Some(min) = &self.min
+            && value < min
+        {
+            return Err("Value is less than min");
+        }
+
+        if let Some(max) = &self.max
+            && value > max
+        {
+            return Err("Value exceeds max");
+        }
+
+        if let Some(step) = self.step {
+            let (base, base_nano) = self
+                .min
+                .as_ref()
+                .map_or((0, 0), |min| (civil::seconds(min), min.hms_nano().3));
+            let seconds = civil::seconds(value) - base;
+            if value.hms_nano().3 != base_nano || seconds % i64::from(step.max(1)) != 0 {
+                return Err("Value doesn't match the step");
+            }
+        }
+
+        Ok(*value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Value::Success(value)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::NaiveTime;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let time_picker = TimePicker {
+            min: None,
+            max: None,
+            step: None,
+        };
+        let mut intermediate = Value::None;
+        time_picker.parse("09:30", &mut intermediate);
+        assert_eq!(
+            intermediate.inner(),
+            NaiveTime::from_hms_opt(9, 30, 0).as_ref()
+        );
+
+        time_picker.parse("09:30:15", &mut intermediate);
+        assert_eq!(
+            intermediate.inner(),
+            NaiveTime::from_hms_opt(9, 30, 15).as_ref()
+        );
+
+        time_picker.parse("09:30:15.250", &mut intermediate);
+        assert_eq!(
+            intermediate.inner(),
+            NaiveTime::from_hms_milli_opt(9, 30, 15, 250).as_ref()
+        );
+
+        time_picker.parse("25:00", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+    }
+
+    #[test]
+    fn validate() {
+        let time_picker = TimePicker {
+            min: NaiveTime::from_hms_opt(9, 0, 0),
+            max: NaiveTime::from_hms_opt(17, 0, 0),
+            step: Some(15 * 60),
+        };
+        let time = |h, m| Value::Success(NaiveTime::from_hms_opt(h, m, 0).unwrap());
+
+        assert!(time_picker.validate(&time(9, 45)).is_ok());
+        assert_eq!(
+            time_picker.validate(&time(8, 45)),
+            Err("Value is less than min")
+        );
+        assert_eq!(
+            time_picker.validate(&time(17, 15)),
+            Err("Value exceeds max")
+        );
+        assert_eq!(
+            time_picker.validate(&time(9, 50)),
+            Err("Value doesn't match the step")
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            civil::format_time(&NaiveTime::from_hms_opt(9, 5, 0).unwrap()),
+            "09:05"
+        );
+        assert_eq!(
+            civil::format_time(&NaiveTime::from_hms_opt(9, 5, 7).unwrap()),
+            "09:05:07"
+        );
+    }
+
+    #[test]
+    fn load() {
+        let time_picker = TimePicker {
+            min: None,
+            max: None,
+            step: Some(15 * 60),
+        };
+        let time = NaiveTime::from_hms_opt(9, 15, 0).unwrap();
+        assert_eq!(time_picker.validate(&time_picker.load(time)), Ok(time));
+    }
+}
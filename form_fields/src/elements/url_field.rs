@@ -0,0 +1,162 @@
+pub use url::Url;
+
+use super::hints::InputHints;
+use crate::{Descriptor, validation_value::Value};
+
+/// Schemes rejected unless explicitly allowed, as they can run scripts when linked to.
+const DANGEROUS_SCHEMES: [&str; 3] = ["javascript", "data", "vbscript"];
+
+/// Represents a URL input field [`<input type="url">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/url).
+#[derive(Debug, Default)]
+pub struct UrlField {
+    pub placeholder: Option<String>,
+    /// Allowed schemes, e.g. `https`, compared case-insensitively.
+    /// If empty, every scheme except `javascript:`, `data:` and `vbscript:` is allowed.
+    pub schemes: Vec<String>,
+    /// If not empty, the host has to be one of these or a subdomain of them.
+    /// Hosts are compared in their punycode form, ignoring case and a trailing `.`.
+    pub allowed_hosts: Vec<String>,
+    /// Rejected hosts, including their subdomains.
+    pub denied_hosts: Vec<String>,
+    pub hints: InputHints,
+}
+
+/// Normalises a host or configured domain for comparison.
+/// Unicode domains are punycoded like the parsed URL's host, and a trailing `.` is dropped.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim_start_matches('.').trim_end_matches('.');
+    url::Host::parse(host).map_or_else(|_| host.to_ascii_lowercase(), |host| host.to_string())
+}
+
+/// Checks if the host is the domain or one of its subdomains.
+fn host_matches(host: &str, domain: &str) -> bool {
+    let host = normalize_host(host);
+    let domain = normalize_host(domain);
+    host == domain || host.ends_with(&format!(".{domain}"))
+}
+
+impl Descriptor for UrlField {
+    type Value = Url;
+    type Intermediate = Value<Url>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        let value = value.trim();
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        let Ok(parsed_value) = Url::parse(value) else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid URL".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value);
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let url = match intermediate {
+            Value::Success(url) => url,
+            Value::Failure(_, _) => return Err("Invalid URL"),
+            Value::None => return Err("Value is required"),
+        };
+
+        let scheme_allowed = if self.schemes.is_empty() {
+            !DANGEROUS_SCHEMES.contains(&url.scheme())
+        } else {
+            // `url` lowercases the parsed scheme.
+            self.schemes
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+        };
+        if !scheme_allowed {
+            return Err("URL scheme is not allowed");
+        }
+
+        let host = url.host_str().unwrap_or_default();
+        if !self.allowed_hosts.is_empty()
+            && !self.allowed_hosts.iter().any(|allowed| host_matches(host, allowed))
+        {
+            return Err("URL host is not allowed");
+        }
+        if self.denied_hosts.iter().any(|denied| host_matches(host, denied)) {
+            return Err("URL host is not allowed");
+        }
+
+        Ok(url.clone())
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Value::Success(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validate(descriptor: &UrlField, value: &str) -> Result<Url, String> {
+        let mut intermediate = Value::None;
+        descriptor.parse(value, &mut intermediate);
+        descriptor.validate(&intermediate).map_err(str::to_string)
+    }
+
+    #[test]
+    fn default_schemes() {
+        let descriptor = UrlField::default();
+        assert!(validate(&descriptor, "https://example.com/hook").is_ok());
+        assert!(validate(&descriptor, "mailto:user@example.com").is_ok());
+        assert!(validate(&descriptor, "javascript:alert(1)").is_err());
+        assert!(validate(&descriptor, "JavaScript:alert(1)").is_err());
+        assert!(validate(&descriptor, "data:text/html,<script>").is_err());
+        assert!(validate(&descriptor, "example.com").is_err());
+    }
+
+    #[test]
+    fn schemes_and_hosts() {
+        let descriptor = UrlField {
+            schemes: vec!["https".to_string()],
+            allowed_hosts: vec!["example.com".to_string()],
+            denied_hosts: vec!["internal.example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(validate(&descriptor, "https://example.com").is_ok());
+        assert!(validate(&descriptor, "https://hooks.Example.com/a").is_ok());
+        assert_eq!(
+            validate(&descriptor, "http://example.com"),
+            Err("URL scheme is not allowed".to_string())
+        );
+        assert!(validate(&descriptor, "https://badexample.com").is_err());
+        assert!(validate(&descriptor, "https://api.internal.example.com").is_err());
+        assert!(validate(&descriptor, "https://internal.example.com./").is_err());
+        assert!(validate(&descriptor, "https://example.com./").is_ok());
+
+        let descriptor = UrlField {
+            schemes: vec!["HTTPS".to_string()],
+            ..Default::default()
+        };
+        assert!(validate(&descriptor, "HTTPS://example.com").is_ok());
+        assert!(validate(&descriptor, "http://example.com").is_err());
+    }
+
+    #[test]
+    fn unicode_hosts() {
+        let descriptor = UrlField {
+            allowed_hosts: vec!["bücher.example".to_string()],
+            denied_hosts: vec!["Intern.Bücher.example".to_string()],
+            ..Default::default()
+        };
+        assert!(validate(&descriptor, "https://bücher.example").is_ok());
+        assert!(validate(&descriptor, "https://xn--bcher-kva.example").is_ok());
+        assert!(validate(&descriptor, "https://shop.bücher.example").is_ok());
+        assert!(validate(&descriptor, "https://intern.xn--bcher-kva.example./").is_err());
+        assert!(validate(&descriptor, "https://buecher.example").is_err());
+    }
+
+    #[test]
+    fn load() {
+        let descriptor = UrlField::default();
+        let url = Url::parse("https://example.com/hook").unwrap();
+        assert_eq!(descriptor.validate(&descriptor.load(url.clone())), Ok(url));
+    }
+}
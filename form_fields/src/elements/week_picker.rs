@@ -0,0 +1,135 @@
+use super::civil::{self, CivilDate};
+use crate::{Descriptor, validation_value::Value};
+
+/// Represents a week picker input [`<input type="week">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/week).
+/// The ISO week is stored as its Monday.
+#[derive(Debug)]
+pub struct WeekPicker<
+    #[cfg(feature = "chrono")] T = chrono::NaiveDate,
+    #[cfg(not(feature = "chrono"))] T,
+> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// Step in weeks, counted from `min` or 1970-W01.
+    pub step: Option<u32>,
+}
+
+impl<T: CivilDate> Descriptor for WeekPicker<T> {
+    type Value = T;
+    type Intermediate = Value<T>;
+
+    fn parse(&self, value: &str, intermediate: &mut Self::Intermediate) {
+        if value.is_empty() {
+            *intermediate = Value::None;
+            return;
+        }
+
+        let Some(parsed_value) = civil::parse_week(value) else {
+            *intermediate = Value::Failure(value.to_string(), "Invalid week".to_string());
+            return;
+        };
+
+        *intermediate = Value::Success(parsed_value)
+    }
+
+    fn validate(&self, intermediate: &Self::Intermediate) -> Result<Self::Value, &'_ str> {
+        let value = intermediate.inner().ok_or("Value is required")?;
+        let days = civil::days(&civil::week_monday(value));
+
+        if let Some(min) = &self.min
+            && days < civil::days(&civil::week_monday(min))
+        {
+            return Err("Value is less than min");
+        }
+
+        if let Some(max) = &self.max
+            && days > civil::days(&civil::week_monday(max))
+        {
+            return Err("Value exceeds max");
+        }
+
+        if let Some(step) = self.step {
+            // 1970-W01 started on Monday, 1969-12-29.
+            let base = self
+                .min
+                .as_ref()
+                .map_or(-3, |min| civil::days(&civil::week_monday(min)));
+            if (days - base) / 7 % i64::from(step.max(1)) != 0 {
+                return Err("Value doesn't match the step");
+            }
+        }
+
+        Ok(*value)
+    }
+
+    fn load(&self, value: Self::Value) -> Self::Intermediate {
+        Value::Success(civil::week_monday(&value))
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::{NaiveDate, Weekday};
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let picker = WeekPicker {
+            min: None,
+            max: None,
+            step: None,
+        };
+        let mut intermediate = Value::None;
+        picker.parse("2025-W01", &mut intermediate);
+        assert_eq!(
+            intermediate.inner(),
+            NaiveDate::from_ymd_opt(2024, 12, 30).as_ref()
+        );
+
+        picker.parse("2024-W54", &mut intermediate);
+        assert!(matches!(intermediate, Value::Failure(_, _)));
+    }
+
+    #[test]
+    fn validate() {
+        let picker = WeekPicker {
+            min: NaiveDate::from_ymd_opt(2024, 1, 3),
+            max: None,
+            step: Some(2),
+        };
+        let week = |w| Value::Success(NaiveDate::from_isoywd_opt(2024, w, Weekday::Mon).unwrap());
+
+        assert!(picker.validate(&week(1)).is_ok());
+        assert!(picker.validate(&week(3)).is_ok());
+        assert_eq!(
+            picker.validate(&week(2)),
+            Err("Value doesn't match the step")
+        );
+        assert_eq!(
+            picker.validate(&Value::Success(
+                NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()
+            )),
+            Err("Value is less than min")
+        );
+        assert_eq!(
+            civil::format_week(&NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()),
+            "2025-W01"
+        );
+    }
+
+    #[test]
+    fn load() {
+        let picker = WeekPicker {
+            min: None,
+            max: None,
+            step: None,
+        };
+        // Loaded dates are moved to the Monday of their week.
+        let intermediate = picker.load(NaiveDate::from_ymd_opt(2024, 3, 6).unwrap());
+        assert_eq!(
+            picker.validate(&intermediate),
+            Ok(NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())
+        );
+    }
+}
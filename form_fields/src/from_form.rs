@@ -1,3 +1,9 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use axum::extract::FromRef;
+
+use crate::selectable::Selectable;
+
 /// Either urlencoded or multipart has to be enabled
 #[cfg(not(any(feature = "urlencoded", feature = "multipart")))]
 compile_error!("Either the 'urlencoded' or 'multipart' feature must be enabled.");
@@ -7,10 +13,45 @@ pub trait FormSpecable {
 }
 
 pub trait FormSpec: Send {
+    /// Whether any field requires the form to be submitted as `multipart/form-data`.
+    const MULTIPART: bool;
+
     fn generate_spec() -> Self;
     fn parse_field(&mut self, name: &str, value: &str) -> bool;
 }
 
+/// Loads the options of a select, radio button or multiselect field when a request is extracted.
+/// Named by the `options_from` attribute, e.g. `#[select(options_from = CarSource, ...)]`.
+///
+/// `S` is the axum state, so options can be loaded from a database pool.
+/// Synchronous sources simply don't await anything.
+pub trait OptionsSource<S> {
+    type Option: Selectable;
+
+    fn options(state: &S) -> impl Future<Output = Vec<Self::Option>> + Send;
+}
+
+/// Options from a map of keys and labels in the axum state, ordered by key.
+/// Used as `options_from = MapOptions<i64>` on a field of type `(i64, String)`.
+pub struct MapOptions<K>(PhantomData<K>);
+
+impl<S, K> OptionsSource<S> for MapOptions<K>
+where
+    BTreeMap<K, String>: FromRef<S>,
+    K: Clone + PartialEq + ToString + std::str::FromStr + Send,
+{
+    type Option = (K, String);
+
+    fn options(state: &S) -> impl Future<Output = Vec<Self::Option>> + Send {
+        std::future::ready(BTreeMap::from_ref(state).into_iter().collect())
+    }
+}
+
+/// Fills the options of all fields with an `options_from` attribute. Implemented by the derive macro.
+pub trait PopulateOptions<S>: FormSpec {
+    fn populate_options(&mut self, state: &S) -> impl Future<Output = ()> + Send;
+}
+
 pub struct FromForm<T>(pub T::Spec)
 where
     T: FormSpecable;
@@ -18,20 +59,25 @@ where
 impl<Specable, State> axum::extract::FromRequest<State> for FromForm<Specable>
 where
     Specable: FormSpecable,
+    Specable::Spec: PopulateOptions<State>,
     State: Send + Sync,
 {
-    type Rejection = maud::Markup;
+    type Rejection = (axum::http::StatusCode, &'static str);
 
     async fn from_request(
         req: axum::extract::Request<axum::body::Body>,
-        _state: &State,
+        state: &State,
     ) -> Result<Self, Self::Rejection> {
         let method = req.method().clone();
         let mut generated = Specable::Spec::generate_spec();
+        generated.populate_options(state).await;
 
         if method == axum::http::Method::POST {
             if parse_request_body(&mut generated, req).await.is_none() {
-                return Err(maud::html! { ("Failed to load Form contents") });
+                return Err((
+                    axum::http::StatusCode::BAD_REQUEST,
+                    "Failed to load Form contents",
+                ));
             }
 
             Ok(Self(generated))
@@ -70,3 +116,75 @@ async fn parse_request_body<Form: FormSpec>(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as form_fields;
+    use axum::{body::Body, extract::FromRequest, http::Request};
+    use form_fields_macro::FromForm;
+
+    struct Sizes;
+
+    impl OptionsSource<Vec<u8>> for Sizes {
+        type Option = u8;
+
+        async fn options(state: &Vec<u8>) -> Vec<u8> {
+            state.clone()
+        }
+    }
+
+    #[derive(FromForm)]
+    struct Order {
+        #[select(display_name = "Size", options_from = Sizes, placeholder = "--")]
+        size: u8,
+        #[multiselect(display_name = "Extras", options_from = Sizes)]
+        extras: Vec<u8>,
+    }
+
+    #[tokio::test]
+    async fn populate_options() {
+        let state = vec![36, 38, 40];
+        let req = Request::get("/").body(Body::empty()).unwrap();
+        let FromForm(form) = FromForm::<Order>::from_request(req, &state).await.unwrap();
+        assert_eq!(form.size.descriptor.options, vec![36, 38, 40]);
+        assert_eq!(form.extras.descriptor.options, vec![36, 38, 40]);
+
+        let req = Request::post("/")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("size=38&extras=40"))
+            .unwrap();
+        let FromForm(mut form) = FromForm::<Order>::from_request(req, &state).await.unwrap();
+        let inner = form.inner().unwrap();
+        assert_eq!(inner.size, 38);
+        assert_eq!(inner.extras, vec![40]);
+
+        let req = Request::post("/")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("size=42"))
+            .unwrap();
+        let FromForm(mut form) = FromForm::<Order>::from_request(req, &state).await.unwrap();
+        assert!(form.inner().is_none());
+    }
+
+    #[derive(FromForm)]
+    struct Filter {
+        #[radio_button(display_name = "Category", options_from = MapOptions<i64>, default_value = (0, String::new()))]
+        category: (i64, String),
+    }
+
+    #[tokio::test]
+    async fn map_options() {
+        let state = BTreeMap::from([(2, "Music".to_string()), (1, "Books".to_string())]);
+        let req = Request::post("/")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("category=2"))
+            .unwrap();
+        let FromForm(mut form) = FromForm::<Filter>::from_request(req, &state).await.unwrap();
+        assert_eq!(
+            form.category.descriptor.options,
+            vec![(1, "Books".to_string()), (2, "Music".to_string())]
+        );
+        assert_eq!(form.inner().unwrap().category, (2, "Music".to_string()));
+    }
+}
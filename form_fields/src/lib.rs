@@ -1,7 +1,9 @@
 pub mod elements;
 pub mod from_form;
+pub mod meta;
 #[cfg(feature = "multipart")]
 pub mod multipart;
+pub mod render;
 pub mod selectable;
 #[cfg(feature = "urlencoded")]
 pub mod urlencoded;
@@ -31,14 +33,16 @@ impl<T> Intermediate for Vec<T> {
 
 /// A trait that describes a form field input element.
 /// Stores and validates data posted from a form.
+/// Rendering is handled separately by the backends in [`render`].
 pub trait Descriptor: Sized {
     type Value;
     type Intermediate: Intermediate;
 
-    /// Renders the form field as HTML markup.
-    /// Preserves previous input values, even if they are invalid.
-    /// Should not try to render error or help messages.
-    fn render(field: &FormField<Self>) -> maud::Markup;
+    /// Whether the field is rendered as a hidden input, without label, help text or error.
+    const HIDDEN: bool = false;
+
+    /// Whether the form has to be submitted as `multipart/form-data`, e.g. for file uploads.
+    const MULTIPART: bool = false;
 
     /// Parses the input value from a string.
     /// If the value is empty, it should set the internal state to `None` or equivalent.
@@ -62,41 +66,77 @@ pub trait Descriptor: Sized {
 pub struct FormField<T: Descriptor> {
     pub display_name: &'static str,
     pub field_name: &'static str,
+    /// Unique id of the rendered input element. Falls back to `field_name` if empty.
+    pub id: &'static str,
     pub descriptor: T,
     pub intermediate: T::Intermediate,
     pub required: bool,
     pub error: Option<String>,
     pub help_text: Option<&'static str>,
+    /// Overrides the globally configured theme for this field.
+    pub theme: Option<&'static render::theme::Theme>,
+    /// Focuses the input element once the page is loaded.
+    pub autofocus: bool,
+    /// Extra HTML attributes of the input element.
+    pub attributes: render::attributes::Attributes,
+    /// Overrides the default layout of the label, input, help text and error.
+    pub layout: Option<render::Layout>,
 }
 
-impl<T: Descriptor> maud::Render for FormField<T> {
-    fn render(&self) -> maud::Markup {
-        maud::html! {
-            div class="form-group" {
-                (T::render(&self))
-                @if let Some(help) = &self.help_text {
-                    div class="help-text" { (help) }
-                }
-                (self.render_error())
-            }
+impl<T: Descriptor> FormField<T> {
+    /// Creates an optional field without a value, e.g. to render a descriptor outside a form spec.
+    pub fn new(display_name: &'static str, field_name: &'static str, descriptor: T) -> Self
+    where
+        T::Intermediate: Default,
+    {
+        Self {
+            display_name,
+            field_name,
+            id: "",
+            descriptor,
+            intermediate: Default::default(),
+            required: false,
+            error: None,
+            help_text: None,
+            theme: None,
+            autofocus: false,
+            attributes: Default::default(),
+            layout: None,
         }
     }
-}
 
-impl<T: Descriptor> FormField<T> {
     pub fn set_error(&mut self, error: String) {
         self.error = Some(error);
     }
 
-    fn render_error(&self) -> maud::Markup {
-        if let Some(ref error) = self.error {
-            maud::html! {
-                div class="error" { (error) }
-            }
+    /// Returns the id of the rendered input element.
+    pub fn id(&self) -> &'static str {
+        if self.id.is_empty() {
+            self.field_name
         } else {
-            maud::html! {}
+            self.id
         }
     }
+
+    /// Whether the field is rendered as a hidden input.
+    pub fn is_hidden(&self) -> bool {
+        T::HIDDEN
+    }
+
+    /// Sets an extra HTML attribute on the input element, e.g. `hx-get`.
+    pub fn set_attribute(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.attributes.set(name, value);
+    }
+
+    /// Adds a class to the input element, in addition to the classes of the theme.
+    pub fn add_class(&mut self, class: &str) {
+        self.attributes.add_class(class);
+    }
+
+    /// Returns the theme used to render this field.
+    pub fn theme(&self) -> &'static render::theme::Theme {
+        self.theme.unwrap_or_else(render::theme::Theme::default_theme)
+    }
 }
 
 #[cfg(feature = "derive")]
@@ -104,3 +144,130 @@ extern crate form_fields_macro;
 
 #[cfg(feature = "derive")]
 pub use form_fields_macro::{FromForm, Selectable};
+
+#[cfg(feature = "maud")]
+#[doc(hidden)]
+pub use maud as __maud;
+
+/// Implements `maud::Render` for a generated form spec, rendering the complete `<form>`.
+/// Expands to nothing if the `maud` feature is disabled.
+#[cfg(feature = "maud")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_render_form {
+    ($spec:ty { $($field:ident),* }) => {
+        impl $crate::__maud::Render for $spec {
+            fn render(&self) -> $crate::__maud::Markup {
+                $crate::render::maud::render_form(
+                    &self.meta,
+                    <Self as $crate::from_form::FormSpec>::MULTIPART,
+                    &[$((
+                        stringify!($field),
+                        self.$field.is_hidden(),
+                        &self.$field as &dyn $crate::__maud::Render,
+                    )),*],
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "maud"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_render_form {
+    ($($tt:tt)*) => {};
+}
+
+/// Creates the [`Pattern`](elements::Pattern) of a `pattern` attribute.
+/// Fails to compile if the `regex` feature is disabled.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pattern {
+    ($pattern:literal) => {
+        $crate::elements::Pattern::new($pattern).expect("invalid pattern")
+    };
+}
+
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pattern {
+    ($pattern:literal) => {
+        compile_error!("`pattern` requires the `regex` feature of form_fields")
+    };
+}
+
+/// Sets the `punycode` flag of an email field, which only exists with the `idna` feature.
+/// Fails to compile if it is set and the `idna` feature is disabled.
+#[cfg(feature = "idna")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __punycode {
+    ($email_field:ident, $punycode:literal) => {
+        $email_field.punycode = $punycode;
+    };
+}
+
+#[cfg(not(feature = "idna"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __punycode {
+    ($email_field:ident, false) => {};
+    ($email_field:ident, true) => {
+        compile_error!("`punycode` requires the `idna` feature of form_fields")
+    };
+}
+
+/// Names or builds a decimal field, which only exists with the `decimal` feature.
+/// Fails to compile if the `decimal` feature is disabled.
+#[cfg(feature = "decimal")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decimal_field {
+    () => {
+        $crate::elements::DecimalField
+    };
+    ({ $($field:tt)* }) => {
+        $crate::elements::DecimalField { $($field)* }
+    };
+}
+
+#[cfg(not(feature = "decimal"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decimal_field {
+    // Stands in for the missing type, so the error below isn't buried under trait errors.
+    () => {
+        $crate::elements::TextField
+    };
+    ({ $($field:tt)* }) => {
+        compile_error!("`decimal_field` requires the `decimal` feature of form_fields")
+    };
+}
+
+/// Implements `maud::Render` for an enum deriving `Selectable`, rendering its label.
+/// Expands to nothing if the `maud` feature is disabled.
+#[cfg(feature = "maud")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_render_selectable {
+    ($ty:ty) => {
+        impl $crate::__maud::Render for $ty {
+            fn render_to(&self, buffer: &mut String) {
+                $crate::__maud::Render::render_to(
+                    $crate::selectable::Selectable::display_value(self),
+                    buffer,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "maud"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_render_selectable {
+    ($($tt:tt)*) => {};
+}
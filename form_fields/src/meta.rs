@@ -0,0 +1,275 @@
+use crate::render::theme::Theme;
+
+/// Settings of the `<form>` element that wraps every field of a form spec.
+/// Defaults can be set with the `#[form(...)]` attribute on the struct and
+/// changed at runtime through the `meta` field of the generated spec.
+#[derive(Debug, Clone)]
+pub struct FormMeta {
+    pub action: Option<String>,
+    pub method: FormMethod,
+    /// Overrides the encoding type, which is otherwise derived from the fields.
+    pub enctype: Option<&'static str>,
+    /// Additional hidden inputs, e.g. a redirect target.
+    pub hidden: Vec<HiddenInput>,
+    /// Hidden input holding the expected CSRF token.
+    pub csrf: Option<HiddenInput>,
+    /// The CSRF token submitted with the form. Checked with [`FormMeta::verify_csrf`].
+    pub submitted_csrf: Option<String>,
+    /// The submit button. Set to `None` to render the button yourself.
+    pub submit: Option<SubmitButton>,
+    /// Overrides the globally configured theme for the form element.
+    pub theme: Option<&'static Theme>,
+    /// Layout of the fields when rendering the complete form.
+    pub placements: Vec<FieldPlacement>,
+    /// Errors that don't belong to a single field, shown in the error summary.
+    pub errors: Vec<String>,
+}
+
+/// The HTTP method of a form. HTML forms can only submit GET and POST.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FormMethod {
+    Get,
+    #[default]
+    Post,
+}
+
+impl FormMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
+
+/// Placement of a field when rendering the complete form.
+/// Fields are sorted by `order`, then by declaration order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FieldPlacement {
+    /// Identifier of the field in the generated spec.
+    pub field: &'static str,
+    /// Fields of the same section are grouped in a `<fieldset>` with the section as `<legend>`.
+    pub section: Option<&'static str>,
+    /// Fields of the same row are rendered side by side.
+    pub row: Option<&'static str>,
+    pub order: i32,
+}
+
+/// Every error of a form, rendered as a list of links to the invalid fields.
+/// Created by the generated `error_summary` of a form spec.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ErrorSummary {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub theme: &'static Theme,
+    pub entries: Vec<ErrorEntry>,
+}
+
+/// A single error of the error summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ErrorEntry {
+    pub message: String,
+    /// Display name of the invalid field. `None` for form-level errors.
+    pub label: Option<&'static str>,
+    /// Id of the invalid input element. `None` for form-level errors.
+    pub id: Option<&'static str>,
+}
+
+impl ErrorSummary {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a heading like `2 problems`.
+    pub fn title(&self) -> String {
+        match self.entries.len() {
+            1 => "1 problem".to_string(),
+            n => format!("{n} problems"),
+        }
+    }
+}
+
+/// A hidden input rendered into the form.
+/// Its value is overwritten with the submitted value when the form is parsed.
+#[derive(Debug, Clone, Default)]
+pub struct HiddenInput {
+    pub name: String,
+    pub value: String,
+}
+
+/// The submit button of a form.
+#[derive(Debug, Clone)]
+pub struct SubmitButton {
+    pub label: String,
+    pub name: Option<String>,
+    pub value: Option<String>,
+}
+
+impl Default for FormMeta {
+    fn default() -> Self {
+        Self {
+            action: None,
+            method: FormMethod::default(),
+            enctype: None,
+            hidden: Vec::new(),
+            csrf: None,
+            submitted_csrf: None,
+            submit: Some(SubmitButton::default()),
+            theme: None,
+            placements: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl Default for SubmitButton {
+    fn default() -> Self {
+        Self {
+            label: "Submit".to_string(),
+            name: None,
+            value: None,
+        }
+    }
+}
+
+impl HiddenInput {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl FormMeta {
+    /// Sets the value of the hidden input with the given name, adding it if it doesn't exist.
+    pub fn set_hidden(&mut self, name: &str, value: impl Into<String>) {
+        match self.hidden.iter_mut().find(|input| input.name == name) {
+            Some(input) => input.value = value.into(),
+            None => self.hidden.push(HiddenInput::new(name, value)),
+        }
+    }
+
+    /// Returns the value of the hidden input with the given name.
+    pub fn hidden_value(&self, name: &str) -> Option<&str> {
+        self.hidden
+            .iter()
+            .find(|input| input.name == name)
+            .map(|input| input.value.as_str())
+    }
+
+    /// Sets the CSRF token, using `name` as the name of the hidden input.
+    pub fn set_csrf(&mut self, name: impl Into<String>, token: impl Into<String>) {
+        self.csrf = Some(HiddenInput::new(name, token));
+    }
+
+    /// Returns the expected CSRF token, as rendered into the form.
+    pub fn csrf_token(&self) -> Option<&str> {
+        self.csrf.as_ref().map(|csrf| csrf.value.as_str())
+    }
+
+    /// Checks that the submitted CSRF token matches the expected one, in constant time.
+    /// Fails if no token was set or submitted.
+    pub fn verify_csrf(&self) -> bool {
+        let (Some(expected), Some(submitted)) = (self.csrf_token(), &self.submitted_csrf) else {
+            return false;
+        };
+        if expected.is_empty() || expected.len() != submitted.len() {
+            return false;
+        }
+        expected
+            .bytes()
+            .zip(submitted.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+
+    /// Stores the value of a submitted hidden or CSRF input.
+    /// The expected CSRF token is kept, the submitted one is stored separately.
+    /// Returns `false` if the name doesn't belong to one of them.
+    pub fn parse_field(&mut self, name: &str, value: &str) -> bool {
+        if self.csrf.as_ref().is_some_and(|csrf| csrf.name == name) {
+            self.submitted_csrf = Some(value.to_string());
+            return true;
+        }
+        match self.hidden.iter_mut().find(|input| input.name == name) {
+            Some(input) => {
+                input.value = value.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the encoding type of the form.
+    /// Uses `multipart/form-data` if any field requires it.
+    pub fn enctype(&self, multipart: bool) -> Option<&'static str> {
+        self.enctype.or(multipart.then_some("multipart/form-data"))
+    }
+
+    /// Returns the placement of the field with the given identifier.
+    pub fn placement(&self, field: &str) -> FieldPlacement {
+        self.placements
+            .iter()
+            .find(|placement| placement.field == field)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Adds an error that doesn't belong to a single field, e.g. from a failed login.
+    pub fn add_error(&mut self, error: impl Into<String>) {
+        self.errors.push(error.into());
+    }
+
+    /// Returns the theme used to render the form element.
+    pub fn theme(&self) -> &'static Theme {
+        self.theme.unwrap_or_else(Theme::default_theme)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_field() {
+        let mut meta = FormMeta::default();
+        meta.set_csrf("csrf_token", "expected");
+        meta.set_hidden("next", "/");
+
+        assert!(meta.parse_field("csrf_token", "forged"));
+        assert!(meta.parse_field("next", "/done"));
+        assert!(!meta.parse_field("other", "value"));
+
+        assert_eq!(meta.csrf_token(), Some("expected"));
+        assert_eq!(meta.submitted_csrf.as_deref(), Some("forged"));
+        assert_eq!(meta.hidden_value("next"), Some("/done"));
+    }
+
+    #[test]
+    fn verify_csrf() {
+        let mut meta = FormMeta::default();
+        assert!(!meta.verify_csrf());
+
+        meta.set_csrf("csrf_token", "expected");
+        assert!(!meta.verify_csrf());
+        meta.parse_field("csrf_token", "forged!!");
+        assert!(!meta.verify_csrf());
+        meta.parse_field("csrf_token", "expected");
+        assert!(meta.verify_csrf());
+
+        meta.set_csrf("csrf_token", "");
+        meta.parse_field("csrf_token", "");
+        assert!(!meta.verify_csrf());
+    }
+
+    #[test]
+    fn enctype() {
+        let mut meta = FormMeta::default();
+        assert_eq!(meta.enctype(false), None);
+        assert_eq!(meta.enctype(true), Some("multipart/form-data"));
+        meta.enctype = Some("application/x-www-form-urlencoded");
+        assert_eq!(meta.enctype(true), Some("application/x-www-form-urlencoded"));
+    }
+}
@@ -0,0 +1,110 @@
+/// Extra HTML attributes rendered on the input element of a field,
+/// e.g. `data-*`, `hx-*` or `style`.
+/// Classes are appended to the classes of the theme.
+/// Attributes the field sets itself, like `id`, `name` or `value`, are reserved and can't be set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Attributes(Vec<(String, String)>);
+
+impl Attributes {
+    /// Sets an attribute, replacing a previous value. Names are case-insensitive, like in HTML.
+    /// Reserved attributes are ignored and attributes with invalid names are skipped when rendering.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        if RESERVED.contains(&name.to_ascii_lowercase().as_str()) {
+            return;
+        }
+        let value = value.into();
+        match self.0.iter_mut().find(|(n, _)| n.eq_ignore_ascii_case(&name)) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((name, value)),
+        }
+    }
+
+    /// Adds a class to the `class` attribute.
+    pub fn add_class(&mut self, class: &str) {
+        match self.0.iter_mut().find(|(n, _)| n.eq_ignore_ascii_case("class")) {
+            Some((_, classes)) if !classes.is_empty() => {
+                classes.push(' ');
+                classes.push_str(class);
+            }
+            _ => self.set("class", class),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the extra classes.
+    pub fn class(&self) -> &str {
+        self.get("class").unwrap_or("")
+    }
+
+    /// Returns every attribute with a valid name, except `class`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("class") && is_valid_name(name))
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Attributes that belong to the field and its value.
+const RESERVED: &[&str] = &[
+    "id", "name", "type", "value", "checked", "selected", "required", "disabled", "form",
+];
+
+/// Checks that the name can't break out of the element.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(|c| {
+            !c.is_whitespace() && !c.is_control() && !matches!(c, '"' | '\'' | '>' | '<' | '/' | '=')
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn attributes() {
+        let mut attributes = Attributes::default();
+        attributes.add_class("wide");
+        attributes.set("data-controller", "mask");
+        attributes.add_class("dark");
+        attributes.set("data-controller", "input");
+        attributes.set("onclick=\"alert(1)\"", "");
+        attributes.set("name", "other");
+        attributes.set("Value", "injected");
+
+        assert_eq!(attributes.class(), "wide dark");
+        assert_eq!(
+            attributes.iter().collect::<Vec<_>>(),
+            vec![("data-controller", "input")]
+        );
+    }
+
+    #[test]
+    fn case_insensitive_names() {
+        let mut attributes = Attributes::default();
+        attributes.set("data-controller", "mask");
+        attributes.set("Data-Controller", "input");
+        attributes.set("CLASS", "wide");
+        attributes.add_class("dark");
+
+        assert_eq!(attributes.class(), "wide dark");
+        assert_eq!(attributes.get("DATA-CONTROLLER"), Some("input"));
+        assert_eq!(
+            attributes.iter().collect::<Vec<_>>(),
+            vec![("data-controller", "input")]
+        );
+    }
+}
@@ -0,0 +1,621 @@
+use std::fmt::Debug;
+
+use super::{Renderer, attributes::Attributes};
+use crate::{
+    Descriptor, FormField,
+    elements::{
+        Checkbox, DatePicker, DateTimePicker, EmailField, InputHints, MonthPicker, MultiSelect,
+        NumberField, Passthrough, PasswordField, RadioButton, Select, TextArea, TextField,
+        TimePicker, WeekPicker,
+        civil::{self, CivilDate, CivilDateTime, CivilTime},
+        email_field::EmailValue,
+        multiselect::MultiSelectMode,
+        number_field::Number,
+    },
+    selectable::Selectable,
+};
+
+/// Exports form fields as plain view models for template engines like
+/// minijinja, askama or tera.
+#[derive(Debug)]
+pub struct Context;
+
+/// View model of a single form field.
+/// With the `serde` feature enabled, it can be serialized into any template context.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldContext {
+    pub name: &'static str,
+    pub id: String,
+    pub label: &'static str,
+    /// The kind of input, e.g. `text`, `number`, `select` or `multiselect`.
+    /// Multiselects rendered as `<select multiple>` or switches are `select-multiple` and `switches`.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub input_type: &'static str,
+    /// The current value, for inputs holding a single value.
+    pub value: Option<String>,
+    /// The current values, for inputs holding multiple values.
+    pub values: Vec<String>,
+    pub options: Vec<OptionContext>,
+    /// Values for a `<datalist>`, linked by the `list` attribute with id `{id}-suggestions`.
+    pub suggestions: Vec<String>,
+    pub placeholder: Option<String>,
+    pub checked: bool,
+    pub constraints: Constraints,
+    pub hints: InputHints,
+    /// Extra HTML attributes of the input element.
+    pub attributes: Attributes,
+    pub required: bool,
+    pub error: Option<String>,
+    pub help: Option<&'static str>,
+}
+
+/// A single option of a radio button, select or multiselect field.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OptionContext {
+    pub value: String,
+    pub label: String,
+    pub selected: bool,
+    /// The `<optgroup>` of a select option.
+    pub group: Option<String>,
+    pub disabled: bool,
+    pub description: Option<String>,
+    /// Extra HTML attributes of the option's element.
+    pub attributes: Attributes,
+}
+
+/// Constraints of a field, formatted as their HTML attribute values.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Constraints {
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub step: Option<String>,
+}
+
+impl FieldContext {
+    /// Creates a context with the values shared between every field type.
+    pub fn new<T: Descriptor>(field: &FormField<T>, input_type: &'static str) -> Self {
+        Self {
+            name: field.field_name,
+            id: field.id().to_string(),
+            label: field.display_name,
+            input_type,
+            value: None,
+            values: Vec::new(),
+            options: Vec::new(),
+            suggestions: Vec::new(),
+            placeholder: None,
+            checked: false,
+            constraints: Constraints::default(),
+            hints: InputHints::default(),
+            attributes: field.attributes.clone(),
+            required: field.required,
+            error: field.error.clone(),
+            help: field.help_text,
+        }
+    }
+}
+
+/// Checks if the key belongs to a disabled option.
+fn is_disabled<T: Selectable>(options: &[T], key: &T::Key) -> bool {
+    options
+        .iter()
+        .any(|option| &option.key() == key && option.disabled())
+}
+
+/// A disabled option is never selected, as browsers don't submit disabled controls.
+fn option_context<T: Selectable>(option: &T, selected: bool) -> OptionContext {
+    OptionContext {
+        value: option.key().to_string(),
+        label: option.display_value().to_string(),
+        selected: selected && !option.disabled(),
+        group: option.group(),
+        disabled: option.disabled(),
+        description: option.description(),
+        attributes: option.attributes(),
+    }
+}
+
+/// `list` is passed as an extra attribute when there are suggestions.
+impl Renderer<TextField> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<TextField>) -> FieldContext {
+        let self_ = &field.descriptor;
+        let mut attributes = field.attributes.clone();
+        if !self_.suggestions.is_empty() {
+            attributes.set("list", format!("{}-suggestions", field.id()));
+        }
+        FieldContext {
+            value: field.intermediate.clone(),
+            placeholder: self_.placeholder.clone(),
+            constraints: Constraints {
+                min_length: self_.min_length,
+                max_length: self_.max_length,
+                pattern: self_.pattern.as_ref().map(|p| p.as_str().to_string()),
+                ..Default::default()
+            },
+            hints: self_.hints.clone(),
+            suggestions: self_.suggestions.clone(),
+            attributes,
+            ..FieldContext::new(field, "text")
+        }
+    }
+}
+
+/// `rows`, `cols` and `wrap` are passed as extra attributes.
+impl<T> Renderer<TextArea<T>> for Context
+where
+    TextArea<T>: Descriptor<Intermediate = Option<String>>,
+{
+    type Output = FieldContext;
+
+    fn render(field: &FormField<TextArea<T>>) -> FieldContext {
+        let self_ = &field.descriptor;
+        let mut attributes = field.attributes.clone();
+        if let Some(rows) = self_.rows {
+            attributes.set("rows", rows.to_string());
+        }
+        if let Some(cols) = self_.cols {
+            attributes.set("cols", cols.to_string());
+        }
+        if let Some(wrap) = &self_.wrap {
+            attributes.set("wrap", wrap.clone());
+        }
+        FieldContext {
+            value: field.intermediate.clone(),
+            placeholder: self_.placeholder.clone(),
+            constraints: Constraints {
+                min_length: self_.min_length,
+                max_length: self_.max_length,
+                ..Default::default()
+            },
+            attributes,
+            ..FieldContext::new(field, "textarea")
+        }
+    }
+}
+
+/// `multiple` is passed as an extra attribute.
+impl<T: EmailValue> Renderer<EmailField<T>> for Context
+where
+    EmailField<T>: Descriptor<Intermediate = Option<String>>,
+{
+    type Output = FieldContext;
+
+    fn render(field: &FormField<EmailField<T>>) -> FieldContext {
+        let self_ = &field.descriptor;
+        let mut attributes = field.attributes.clone();
+        if self_.multiple() {
+            attributes.set("multiple", "");
+        }
+        FieldContext {
+            value: field.intermediate.clone(),
+            placeholder: self_.placeholder.clone(),
+            constraints: Constraints {
+                max_length: self_.max_length,
+                ..Default::default()
+            },
+            hints: self_.hints.clone(),
+            attributes,
+            ..FieldContext::new(field, "email")
+        }
+    }
+}
+
+impl Renderer<PasswordField> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<PasswordField>) -> FieldContext {
+        let self_ = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.clone(),
+            constraints: Constraints {
+                min_length: self_.min_length,
+                max_length: self_.max_length,
+                pattern: self_.pattern.as_ref().map(|p| p.as_str().to_string()),
+                ..Default::default()
+            },
+            hints: self_.hints.clone(),
+            ..FieldContext::new(field, "password")
+        }
+    }
+}
+
+impl<T: Number> Renderer<NumberField<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<NumberField<T>>) -> FieldContext {
+        let self_ = &field.descriptor;
+        let NumberField { min, max, hints, .. } = self_;
+        let hints = InputHints {
+            inputmode: self_.inputmode().map(str::to_string),
+            ..hints.clone()
+        };
+        // Localized fields are text inputs, which have no min, max and step.
+        let number = self_.locale.is_none();
+        FieldContext {
+            value: field.intermediate.map(|v| self_.format(v)),
+            constraints: Constraints {
+                min: min.filter(|_| number).map(|v| v.to_string()),
+                max: max.filter(|_| number).map(|v| v.to_string()),
+                step: self_.step_attribute().filter(|_| number),
+                ..Default::default()
+            },
+            hints,
+            ..FieldContext::new(field, if number { "number" } else { "text" })
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Renderer<crate::elements::DecimalField> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<crate::elements::DecimalField>) -> FieldContext {
+        let self_ = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(|v| v.to_string()),
+            constraints: Constraints {
+                min: self_.min.map(|v| v.to_string()),
+                max: self_.max.map(|v| v.to_string()),
+                step: Some(self_.step_attribute()),
+                ..Default::default()
+            },
+            hints: self_.hints.clone(),
+            ..FieldContext::new(field, "number")
+        }
+    }
+}
+
+impl<T: CivilDate> Renderer<DatePicker<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<DatePicker<T>>) -> FieldContext {
+        let DatePicker { min, max } = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(civil::format_date),
+            constraints: Constraints {
+                min: min.as_ref().map(civil::format_date),
+                max: max.as_ref().map(civil::format_date),
+                ..Default::default()
+            },
+            ..FieldContext::new(field, "date")
+        }
+    }
+}
+
+impl<T: CivilTime> Renderer<TimePicker<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<TimePicker<T>>) -> FieldContext {
+        let TimePicker { min, max, step } = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(civil::format_time),
+            constraints: Constraints {
+                min: min.as_ref().map(civil::format_time),
+                max: max.as_ref().map(civil::format_time),
+                step: step.map(|v| v.to_string()),
+                ..Default::default()
+            },
+            ..FieldContext::new(field, "time")
+        }
+    }
+}
+
+impl<T: CivilDateTime> Renderer<DateTimePicker<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<DateTimePicker<T>>) -> FieldContext {
+        let DateTimePicker { min, max, step } = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(civil::format_datetime),
+            constraints: Constraints {
+                min: min.as_ref().map(civil::format_datetime),
+                max: max.as_ref().map(civil::format_datetime),
+                step: step.map(|v| v.to_string()),
+                ..Default::default()
+            },
+            ..FieldContext::new(field, "datetime-local")
+        }
+    }
+}
+
+impl<T: CivilDate> Renderer<MonthPicker<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<MonthPicker<T>>) -> FieldContext {
+        let MonthPicker { min, max, step } = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(civil::format_month),
+            constraints: Constraints {
+                min: min.as_ref().map(civil::format_month),
+                max: max.as_ref().map(civil::format_month),
+                step: step.map(|v| v.to_string()),
+                ..Default::default()
+            },
+            ..FieldContext::new(field, "month")
+        }
+    }
+}
+
+impl<T: CivilDate> Renderer<WeekPicker<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<WeekPicker<T>>) -> FieldContext {
+        let WeekPicker { min, max, step } = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(civil::format_week),
+            constraints: Constraints {
+                min: min.as_ref().map(civil::format_week),
+                max: max.as_ref().map(civil::format_week),
+                step: step.map(|v| v.to_string()),
+                ..Default::default()
+            },
+            ..FieldContext::new(field, "week")
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl Renderer<crate::elements::UrlField> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<crate::elements::UrlField>) -> FieldContext {
+        let self_ = &field.descriptor;
+        FieldContext {
+            value: field.intermediate.map(|v| v.to_string()),
+            placeholder: self_.placeholder.clone(),
+            hints: self_.hints.clone(),
+            ..FieldContext::new(field, "url")
+        }
+    }
+}
+
+impl Renderer<Checkbox> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<Checkbox>) -> FieldContext {
+        let Checkbox {
+            required_true,
+            checked,
+        } = &field.descriptor;
+        FieldContext {
+            value: Some("true".to_string()),
+            checked: field.intermediate.unwrap_or(*checked),
+            required: *required_true,
+            ..FieldContext::new(field, "checkbox")
+        }
+    }
+}
+
+impl<T: Selectable + Debug> Renderer<RadioButton<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<RadioButton<T>>) -> FieldContext {
+        let RadioButton {
+            options,
+            default_value,
+        } = &field.descriptor;
+        let default = default_value.key();
+        let selected = field.intermediate.as_ref().unwrap_or(&default);
+        FieldContext {
+            value: (!is_disabled(options, selected)).then(|| selected.to_string()),
+            options: options
+                .iter()
+                .map(|option| option_context(option, &option.key() == selected))
+                .collect(),
+            ..FieldContext::new(field, "radio")
+        }
+    }
+}
+
+impl<T: Selectable + Debug> Renderer<Select<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<Select<T>>) -> FieldContext {
+        let Select {
+            default_value,
+            options,
+            placeholder,
+        } = &field.descriptor;
+        let default = default_value.as_ref().map(|v| v.key());
+        let selected = field
+            .intermediate
+            .as_ref()
+            .or(default.as_ref())
+            .filter(|key| !is_disabled(options, key));
+        FieldContext {
+            value: selected.map(|key| key.to_string()),
+            options: options
+                .iter()
+                .map(|option| option_context(option, selected == Some(&option.key())))
+                .collect(),
+            placeholder: Some(placeholder.clone()),
+            ..FieldContext::new(field, "select")
+        }
+    }
+}
+
+impl<T: Selectable + Debug> Renderer<MultiSelect<T>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<MultiSelect<T>>) -> FieldContext {
+        let MultiSelect { options, mode } = &field.descriptor;
+        let keys = &field.intermediate;
+        let mut attributes = field.attributes.clone();
+        let input_type = match mode {
+            MultiSelectMode::Checkboxes => "multiselect",
+            MultiSelectMode::Select { size } => {
+                if let Some(size) = size {
+                    attributes.set("size", size.to_string());
+                }
+                "select-multiple"
+            }
+            MultiSelectMode::Switches => "switches",
+        };
+        FieldContext {
+            values: keys
+                .iter()
+                .filter(|key| !is_disabled(options, key))
+                .map(|key| key.to_string())
+                .collect(),
+            options: options
+                .iter()
+                .map(|option| option_context(option, keys.contains(&option.key())))
+                .collect(),
+            attributes,
+            ..FieldContext::new(field, input_type)
+        }
+    }
+}
+
+impl Renderer<Passthrough<String>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<Passthrough<String>>) -> FieldContext {
+        FieldContext {
+            value: field.intermediate.clone(),
+            ..FieldContext::new(field, "hidden")
+        }
+    }
+}
+
+impl Renderer<Passthrough<Vec<String>>> for Context {
+    type Output = FieldContext;
+
+    fn render(field: &FormField<Passthrough<Vec<String>>>) -> FieldContext {
+        FieldContext {
+            values: field.intermediate.clone(),
+            ..FieldContext::new(field, "hidden")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as form_fields;
+    use form_fields_macro::FromForm;
+
+    #[test]
+    fn select_context() {
+        let field = FormField {
+            intermediate: Some(2),
+            required: true,
+            error: Some("Invalid value".to_string()),
+            ..FormField::new(
+                "Pick",
+                "pick",
+                Select {
+                    default_value: Some(1u8),
+                    options: vec![1u8, 2],
+                    placeholder: "--".to_string(),
+                },
+            )
+        };
+        let context = field.render_with::<Context>();
+        assert_eq!(context.name, "pick");
+        assert_eq!(context.input_type, "select");
+        assert_eq!(context.value.as_deref(), Some("2"));
+        assert_eq!(
+            context.options,
+            vec![
+                OptionContext {
+                    value: "1".to_string(),
+                    label: "1".to_string(),
+                    selected: false,
+                    group: None,
+                    disabled: false,
+                    description: None,
+                    attributes: Attributes::default(),
+                },
+                OptionContext {
+                    value: "2".to_string(),
+                    label: "2".to_string(),
+                    selected: true,
+                    group: None,
+                    disabled: false,
+                    description: None,
+                    attributes: Attributes::default(),
+                },
+            ]
+        );
+        assert_eq!(context.error.as_deref(), Some("Invalid value"));
+    }
+
+    #[test]
+    fn number_context() {
+        let field = FormField {
+            intermediate: crate::validation_value::Value::Failure(
+                "abc".to_string(),
+                "Invalid number".to_string(),
+            ),
+            help_text: Some("In years"),
+            ..FormField::new(
+                "Age",
+                "age",
+                NumberField {
+                    min: Some(0),
+                    max: Some(120),
+                    step: None,
+                    locale: None,
+                    hints: InputHints {
+                        inputmode: Some("numeric".to_string()),
+                        ..Default::default()
+                    },
+                },
+            )
+        };
+        let context = field.render_with::<Context>();
+        assert_eq!(context.value.as_deref(), Some("abc"));
+        assert_eq!(context.constraints.min.as_deref(), Some("0"));
+        assert_eq!(context.constraints.max.as_deref(), Some("120"));
+        assert_eq!(context.hints.inputmode.as_deref(), Some("numeric"));
+        assert_eq!(context.help, Some("In years"));
+
+        let field = FormField::new(
+            "Amount",
+            "amount",
+            NumberField {
+                min: Some(0.0),
+                max: Some(1000.0),
+                step: None,
+                locale: Some(crate::elements::NumberLocale::DE),
+                hints: InputHints::default(),
+            },
+        );
+        let context = field.render_with::<Context>();
+        assert_eq!(context.input_type, "text");
+        assert_eq!(context.constraints.min, None);
+        assert_eq!(context.constraints.max, None);
+        assert_eq!(context.constraints.step, None);
+        assert_eq!(context.hints.inputmode.as_deref(), Some("decimal"));
+    }
+
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Mock {
+        #[text_field(display_name = "Field 1")]
+        field1: String,
+        #[checkbox(display_name = "Field 2")]
+        field2: bool,
+    }
+
+    #[test]
+    fn spec_context() {
+        let form = MockFormSpec::new();
+        let context = form.context();
+        assert_eq!(context.len(), 2);
+        assert_eq!(context[0].name, "field1");
+        assert_eq!(context[0].id, "mock-field1");
+        assert_eq!(context[0].input_type, "text");
+        assert_eq!(context[1].name, "field2");
+        assert_eq!(context[1].input_type, "checkbox");
+    }
+}
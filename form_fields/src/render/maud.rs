@@ -0,0 +1,1491 @@
+use std::fmt::{Debug, Write};
+
+use maud::{Markup, PreEscaped, html};
+
+use super::Renderer;
+use crate::{
+    Descriptor, FormField,
+    elements::{
+        Checkbox, DatePicker, DateTimePicker, EmailField, InputHints, MonthPicker, MultiSelect,
+        NumberField, Passthrough, PasswordField, RadioButton, Select, TextArea, TextField,
+        TimePicker, WeekPicker,
+        civil::{self, CivilDate, CivilDateTime, CivilTime},
+        email_field::EmailValue,
+        multiselect::MultiSelectMode,
+        number_field::Number,
+    },
+    meta::{ErrorSummary, FieldPlacement, FormMeta},
+    render::{attributes::Attributes, theme::Theme},
+    selectable::Selectable,
+};
+
+/// Renders form fields to [maud](https://maud.lambda.xyz/) markup.
+#[derive(Debug)]
+pub struct Maud;
+
+/// Renders the label and the control of a field separately, so a [`FieldLayout`] can arrange them.
+pub trait RenderParts<D: Descriptor> {
+    /// The label is rendered next to the control, like for checkboxes.
+    /// The label part then only holds the label text.
+    const INLINE: bool = false;
+
+    /// Renders the label. `None` if the control labels itself, like a `<fieldset>` with `<legend>`.
+    fn label(_field: &FormField<D>) -> Option<Markup> {
+        None
+    }
+
+    /// Renders the input element and its wrappers.
+    fn control(field: &FormField<D>) -> Markup;
+}
+
+impl<D: Descriptor> Renderer<D> for Maud
+where
+    Maud: RenderParts<D>,
+{
+    type Output = Markup;
+
+    fn render(field: &FormField<D>) -> Markup {
+        FieldParts::new(field).labelled_control()
+    }
+}
+
+/// The separately rendered parts of a field, passed to a [`FieldLayout`].
+#[derive(Debug, Clone)]
+pub struct FieldParts {
+    /// Id of the input element.
+    pub id: &'static str,
+    pub theme: &'static Theme,
+    pub label: Option<Markup>,
+    pub control: Markup,
+    /// The label only holds the label text and belongs next to the control.
+    pub inline: bool,
+    pub help: Option<Markup>,
+    pub error: Option<Markup>,
+}
+
+impl FieldParts {
+    pub fn new<T: Descriptor>(field: &FormField<T>) -> Self
+    where
+        Maud: RenderParts<T>,
+    {
+        let theme = field.theme();
+        Self {
+            id: field.id(),
+            theme,
+            label: <Maud as RenderParts<T>>::label(field),
+            control: <Maud as RenderParts<T>>::control(field),
+            inline: <Maud as RenderParts<T>>::INLINE,
+            help: field
+                .help_text
+                .map(|help| render_feedback(theme, theme.help, &help_id(field), help)),
+            error: field
+                .error
+                .as_ref()
+                .map(|error| render_feedback(theme, theme.error, &error_id(field), error)),
+        }
+    }
+
+    /// Renders the label and the control in their default arrangement.
+    pub fn labelled_control(&self) -> Markup {
+        let theme = self.theme;
+        if self.inline {
+            wrap(
+                theme.check,
+                html! {
+                    label class=[classes(&[theme.checkbox_label])] {
+                        (self.control)
+                        @if let Some(label) = &self.label {
+                            (label)
+                        }
+                    }
+                },
+            )
+        } else {
+            html! {
+                @if let Some(label) = &self.label {
+                    (label)
+                }
+                (self.control)
+            }
+        }
+    }
+}
+
+/// Arranges the rendered parts of a field.
+/// Can be set per field, or per form with the generated `set_layout`.
+pub trait FieldLayout: Debug + Sync {
+    fn render(&self, parts: FieldParts) -> Markup;
+}
+
+/// Renders the label and control, followed by help text and error, wrapped in the group of the theme.
+#[derive(Debug)]
+pub struct DefaultLayout;
+
+impl FieldLayout for DefaultLayout {
+    fn render(&self, parts: FieldParts) -> Markup {
+        wrap(
+            parts.theme.group,
+            html! {
+                (parts.labelled_control())
+                @if let Some(help) = &parts.help {
+                    (help)
+                }
+                @if let Some(error) = &parts.error {
+                    (error)
+                }
+            },
+        )
+    }
+}
+
+impl<T: Descriptor> maud::Render for FormField<T>
+where
+    Maud: RenderParts<T>,
+{
+    fn render(&self) -> Markup {
+        if T::HIDDEN {
+            return <Maud as RenderParts<T>>::control(self);
+        }
+        let layout = self.layout.unwrap_or(&DefaultLayout);
+        layout.render(FieldParts::new(self))
+    }
+}
+
+/// Renders a complete `<form>` with its hidden inputs, fields and submit button.
+/// Fields are ordered and grouped into sections and rows by their placement in `meta`.
+/// Hidden fields are rendered before them, outside of sections and rows.
+/// Used by the `maud::Render` implementation of generated form specs.
+pub fn render_form(
+    meta: &FormMeta,
+    multipart: bool,
+    fields: &[(&'static str, bool, &dyn maud::Render)],
+) -> Markup {
+    let theme = meta.theme();
+    let hidden = fields.iter().filter(|(_, hidden, _)| *hidden);
+    let mut fields: Vec<PlacedField> = fields
+        .iter()
+        .filter(|(_, hidden, _)| !hidden)
+        .map(|(name, _, field)| (meta.placement(name), *field))
+        .collect();
+    fields.sort_by_key(|(placement, _)| placement.order);
+
+    html! {
+        form method=(meta.method.as_str()) action=[meta.action.as_deref()] enctype=[meta.enctype(multipart)] {
+            @for input in meta.csrf.iter().chain(&meta.hidden) {
+                input type="hidden" name=(input.name) value=(input.value);
+            }
+            @for (_, _, field) in hidden {
+                (field)
+            }
+            @for (section, fields) in group_by(&fields, |(placement, _)| placement.section) {
+                @if let Some(section) = section {
+                    fieldset class=[classes(&[theme.fieldset])] {
+                        legend class=[classes(&[theme.legend])] { (section) }
+                        (render_rows(theme, &fields))
+                    }
+                } @else {
+                    (render_rows(theme, &fields))
+                }
+            }
+            @if let Some(submit) = &meta.submit {
+                button
+                    type="submit"
+                    class=[classes(&[theme.button])]
+                    name=[submit.name.as_deref()]
+                    value=[submit.value.as_deref()] { (submit.label) }
+            }
+        }
+    }
+}
+
+impl maud::Render for ErrorSummary {
+    fn render(&self) -> Markup {
+        if self.is_empty() {
+            return html! {};
+        }
+        html! {
+            div class=[classes(&[self.theme.error_summary])] role="alert" {
+                h2 { (self.title()) }
+                ul {
+                    @for entry in &self.entries {
+                        li {
+                            @match (entry.id, entry.label) {
+                                (Some(id), Some(label)) => {
+                                    a href={ "#" (id) } { (label) ": " (entry.message) }
+                                }
+                                _ => { (entry.message) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+type PlacedField<'a> = (FieldPlacement, &'a dyn maud::Render);
+
+fn render_rows(theme: &Theme, fields: &[PlacedField]) -> Markup {
+    html! {
+        @for (row, fields) in group_by(fields, |(placement, _)| placement.row) {
+            @if row.is_some() {
+                div class=[classes(&[theme.row])] {
+                    @for (_, field) in fields {
+                        (wrap(theme.column, html! { (field) }))
+                    }
+                }
+            } @else {
+                @for (_, field) in fields {
+                    (field)
+                }
+            }
+        }
+    }
+}
+
+/// Groups items by a key in order of their first appearance.
+/// Items without a key form a group of their own.
+fn group_by<T: Copy, K: PartialEq>(
+    items: &[T],
+    key: impl Fn(&T) -> Option<K>,
+) -> Vec<(Option<K>, Vec<T>)> {
+    let mut groups: Vec<(Option<K>, Vec<_>)> = Vec::new();
+    for &item in items {
+        let group_key = key(&item);
+        let existing = group_key
+            .as_ref()
+            .and_then(|k| groups.iter_mut().find(|(g, _)| g.as_ref() == Some(k)));
+        match existing {
+            Some((_, group)) => group.push(item),
+            None => groups.push((group_key, vec![item])),
+        }
+    }
+    groups
+}
+
+fn render_feedback(theme: &Theme, class: &str, id: &str, text: &str) -> Markup {
+    if theme.small_feedback {
+        html! { small id=(id) class=[classes(&[class])] { (text) } }
+    } else {
+        html! { div id=(id) class=[classes(&[class])] { (text) } }
+    }
+}
+
+fn help_id<T: Descriptor>(field: &FormField<T>) -> String {
+    format!("{}-help", field.id())
+}
+
+fn error_id<T: Descriptor>(field: &FormField<T>) -> String {
+    format!("{}-error", field.id())
+}
+
+fn suggestions_id<T: Descriptor>(field: &FormField<T>) -> String {
+    format!("{}-suggestions", field.id())
+}
+
+fn description_id<T: Descriptor>(field: &FormField<T>, index: usize) -> String {
+    format!("{}-description", option_id(field, index))
+}
+
+/// Links an option to its description, besides the help text and error of the field.
+fn option_describedby<T: Descriptor>(
+    field: &FormField<T>,
+    description_id: Option<&str>,
+) -> Option<String> {
+    match (description_id, aria_describedby(field)) {
+        (Some(description), Some(field)) => Some(format!("{description} {field}")),
+        (description, field) => field.or(description.map(str::to_string)),
+    }
+}
+
+/// Renders the description of a radio button or checkbox option.
+fn render_description(theme: &Theme, id: Option<&str>, description: Option<String>) -> Markup {
+    html! {
+        @if let (Some(id), Some(description)) = (id, description) {
+            small id=(id) class=[classes(&[theme.help])] { (description) }
+        }
+    }
+}
+
+/// Links the input element to the rendered help text and error.
+fn aria_describedby<T: Descriptor>(field: &FormField<T>) -> Option<String> {
+    match (field.help_text.is_some(), field.error.is_some()) {
+        (true, true) => Some(format!("{} {}", help_id(field), error_id(field))),
+        (true, false) => Some(help_id(field)),
+        (false, true) => Some(error_id(field)),
+        (false, false) => None,
+    }
+}
+
+/// The first option of a group uses the id of the field, so it can be linked to.
+fn option_id<T: Descriptor>(field: &FormField<T>, index: usize) -> String {
+    if index == 0 {
+        field.id().to_string()
+    } else {
+        format!("{}-{}", field.id(), index)
+    }
+}
+
+fn render_label<T: Descriptor>(field: &FormField<T>, theme: &Theme) -> Markup {
+    html! {
+        label for=(field.id()) class=[classes(&[theme.label])] { (field.display_name) }
+    }
+}
+
+/// Joins all non-empty classes, returns `None` if there are none.
+fn classes(classes: &[&str]) -> Option<String> {
+    let classes: Vec<&str> = classes.iter().copied().filter(|c| !c.is_empty()).collect();
+    (!classes.is_empty()).then(|| classes.join(" "))
+}
+
+/// Wraps the markup in a `div` with the given class, unless the class is empty.
+fn wrap(class: &str, markup: Markup) -> Markup {
+    if class.is_empty() {
+        markup
+    } else {
+        html! { div class=(class) { (markup) } }
+    }
+}
+
+/// Wraps a select in the control and select wrappers of the theme.
+fn wrap_select<T: Descriptor>(
+    field: &FormField<T>,
+    theme: &Theme,
+    multiple: bool,
+    select: Markup,
+) -> Markup {
+    let invalid = if field.error.is_some() {
+        theme.select_wrapper_invalid
+    } else {
+        ""
+    };
+    let multiple = if multiple {
+        theme.select_wrapper_multiple
+    } else {
+        ""
+    };
+    let wrapper = classes(&[theme.select_wrapper, invalid, multiple]).unwrap_or_default();
+    wrap(theme.control, wrap(&wrapper, select))
+}
+
+fn input_class<T: Descriptor>(field: &FormField<T>, theme: &Theme) -> Option<String> {
+    let invalid = if field.error.is_some() {
+        theme.input_invalid
+    } else {
+        ""
+    };
+    classes(&[theme.input, invalid, field.attributes.class()])
+}
+
+/// An element with extra attributes, which maud can't add to a template.
+/// Its own attributes come first, extra attributes never replace them.
+struct Element {
+    tag: &'static str,
+    html: String,
+    names: Vec<&'static str>,
+}
+
+impl Element {
+    fn new(tag: &'static str) -> Self {
+        Self {
+            tag,
+            html: format!("<{tag}"),
+            names: Vec::new(),
+        }
+    }
+
+    fn attr(mut self, name: &'static str, value: impl maud::Render) -> Self {
+        self.names.push(name);
+        write!(self.html, " {name}=\"").expect("writing to a string can't fail");
+        value.render_to(&mut self.html);
+        self.html.push('"');
+        self
+    }
+
+    fn optional(self, name: &'static str, value: Option<impl maud::Render>) -> Self {
+        match value {
+            Some(value) => self.attr(name, value),
+            None => self,
+        }
+    }
+
+    fn flag(mut self, name: &'static str, set: bool) -> Self {
+        if set {
+            self.names.push(name);
+            self.html.push(' ');
+            self.html.push_str(name);
+        }
+        self
+    }
+
+    fn hints(self, hints: &InputHints) -> Self {
+        self.optional("autocomplete", hints.autocomplete.as_deref())
+            .optional("inputmode", hints.inputmode.as_deref())
+            .optional("spellcheck", hints.spellcheck())
+            .optional("autocapitalize", hints.autocapitalize.as_deref())
+            .optional("size", hints.size)
+            .flag("readonly", hints.readonly)
+    }
+
+    /// Adds the `required`, `autofocus` and `aria-*` attributes of the field.
+    fn state<T: Descriptor>(self, field: &FormField<T>) -> Self {
+        self.flag("required", field.required)
+            .flag("autofocus", field.autofocus)
+            .optional("aria-invalid", aria_invalid(field))
+            .optional("aria-describedby", aria_describedby(field))
+    }
+
+    /// Adds extra attributes, except `class` and those already set.
+    fn extra(mut self, attributes: &Attributes) -> Self {
+        for (name, value) in attributes.iter() {
+            if self.names.iter().any(|set| set.eq_ignore_ascii_case(name)) {
+                continue;
+            }
+            write!(self.html, " {name}=\"").expect("writing to a string can't fail");
+            maud::Render::render_to(value, &mut self.html);
+            self.html.push('"');
+        }
+        self
+    }
+
+    fn finish(mut self, content: Markup) -> Markup {
+        write!(self.html, ">{}</{}>", content.into_string(), self.tag)
+            .expect("writing to a string can't fail");
+        PreEscaped(self.html)
+    }
+}
+
+fn aria_invalid<T: Descriptor>(field: &FormField<T>) -> Option<&'static str> {
+    field.error.is_some().then_some("true")
+}
+
+impl RenderParts<TextField> for Maud {
+    fn label(field: &FormField<TextField>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<TextField>) -> Markup {
+        let self_ = &field.descriptor;
+        let theme = field.theme();
+        let list = (!self_.suggestions.is_empty()).then(|| suggestions_id(field));
+        let input = Element::new("input")
+            .attr("type", "text")
+            .attr("id", field.id())
+            .optional("class", input_class(field, theme))
+            .attr("name", field.field_name)
+            .optional("value", field.intermediate.as_deref())
+            .optional("placeholder", self_.placeholder.as_deref())
+            .optional("minlength", self_.min_length)
+            .optional("maxlength", self_.max_length)
+            .optional("pattern", self_.pattern.as_ref().map(|p| p.as_str()))
+            .optional("list", list.as_deref())
+            .hints(&self_.hints)
+            .state(field)
+            .extra(&field.attributes)
+            .finish(html! {});
+        wrap(
+            theme.control,
+            html! {
+                (input)
+                @if let Some(list) = list {
+                    datalist id=(list) {
+                        @for suggestion in &self_.suggestions {
+                            option value=(suggestion) {}
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
+
+impl<T> RenderParts<TextArea<T>> for Maud
+where
+    TextArea<T>: Descriptor<Intermediate = Option<String>>,
+{
+    fn label(field: &FormField<TextArea<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<TextArea<T>>) -> Markup {
+        let self_ = &field.descriptor;
+        let theme = field.theme();
+        let invalid = if field.error.is_some() {
+            theme.input_invalid
+        } else {
+            ""
+        };
+        // Browsers drop a single leading newline of the content.
+        let value = field.intermediate.as_deref().unwrap_or_default();
+        let leading_newline = if value.starts_with('\n') { "\n" } else { "" };
+        wrap(
+            theme.control,
+            Element::new("textarea")
+                .attr("id", field.id())
+                .optional(
+                    "class",
+                    classes(&[theme.textarea, invalid, field.attributes.class()]),
+                )
+                .attr("name", field.field_name)
+                .optional("rows", self_.rows)
+                .optional("cols", self_.cols)
+                .optional("placeholder", self_.placeholder.as_deref())
+                .optional("minlength", self_.min_length)
+                .optional("maxlength", self_.max_length)
+                .optional("wrap", self_.wrap.as_deref())
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! { (leading_newline) (value) }),
+        )
+    }
+}
+
+impl<T: EmailValue> RenderParts<EmailField<T>> for Maud
+where
+    EmailField<T>: Descriptor<Intermediate = Option<String>>,
+{
+    fn label(field: &FormField<EmailField<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<EmailField<T>>) -> Markup {
+        let self_ = &field.descriptor;
+        let theme = field.theme();
+        wrap(
+            theme.control,
+            Element::new("input")
+                .attr("type", "email")
+                .attr("id", field.id())
+                .optional("class", input_class(field, theme))
+                .attr("name", field.field_name)
+                .optional("value", field.intermediate.as_deref())
+                .optional("placeholder", self_.placeholder.as_deref())
+                .optional("maxlength", self_.max_length)
+                .flag("multiple", self_.multiple())
+                .hints(&self_.hints)
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! {}),
+        )
+    }
+}
+
+#[cfg(feature = "url")]
+impl RenderParts<crate::elements::UrlField> for Maud {
+    fn label(field: &FormField<crate::elements::UrlField>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<crate::elements::UrlField>) -> Markup {
+        let self_ = &field.descriptor;
+        let theme = field.theme();
+        wrap(
+            theme.control,
+            Element::new("input")
+                .attr("type", "url")
+                .attr("id", field.id())
+                .optional("class", input_class(field, theme))
+                .attr("name", field.field_name)
+                .optional("value", field.intermediate.map(|v| v.to_string()))
+                .optional("placeholder", self_.placeholder.as_deref())
+                .hints(&self_.hints)
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! {}),
+        )
+    }
+}
+
+impl RenderParts<PasswordField> for Maud {
+    fn label(field: &FormField<PasswordField>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<PasswordField>) -> Markup {
+        let self_ = &field.descriptor;
+        let theme = field.theme();
+        wrap(
+            theme.control,
+            Element::new("input")
+                .attr("type", "password")
+                .attr("id", field.id())
+                .optional("class", input_class(field, theme))
+                .attr("name", field.field_name)
+                .optional("value", field.intermediate.as_deref())
+                .optional("minlength", self_.min_length)
+                .optional("maxlength", self_.max_length)
+                .optional("pattern", self_.pattern.as_ref().map(|p| p.as_str()))
+                .hints(&self_.hints)
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! {}),
+        )
+    }
+}
+
+impl<T: Number> RenderParts<NumberField<T>> for Maud {
+    fn label(field: &FormField<NumberField<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<NumberField<T>>) -> Markup {
+        let self_ = &field.descriptor;
+        let NumberField { min, max, .. } = self_;
+        let value = field.intermediate.map(|v| self_.format(v));
+        let theme = field.theme();
+        // `type="number"` only accepts the browser's locale. Text inputs have no min, max and step.
+        let number = self_.locale.is_none();
+
+        wrap(
+            theme.control,
+            Element::new("input")
+                .attr("type", if number { "number" } else { "text" })
+                .attr("id", field.id())
+                .optional("class", input_class(field, theme))
+                .attr("name", field.field_name)
+                .optional("value", value)
+                .optional("min", min.filter(|_| number).map(|v| v.to_string()))
+                .optional("max", max.filter(|_| number).map(|v| v.to_string()))
+                .optional("step", self_.step_attribute().filter(|_| number))
+                .optional("autocomplete", self_.hints.autocomplete.as_deref())
+                .optional("inputmode", self_.inputmode())
+                .optional("spellcheck", self_.hints.spellcheck())
+                .optional("autocapitalize", self_.hints.autocapitalize.as_deref())
+                .optional("size", self_.hints.size)
+                .flag("readonly", self_.hints.readonly)
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! {}),
+        )
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl RenderParts<crate::elements::DecimalField> for Maud {
+    fn label(field: &FormField<crate::elements::DecimalField>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<crate::elements::DecimalField>) -> Markup {
+        let self_ = &field.descriptor;
+        let value = field.intermediate.map(|v| v.to_string());
+        let theme = field.theme();
+
+        wrap(
+            theme.control,
+            Element::new("input")
+                .attr("type", "number")
+                .attr("id", field.id())
+                .optional("class", input_class(field, theme))
+                .attr("name", field.field_name)
+                .optional("value", value)
+                .optional("min", self_.min.map(|v| v.to_string()))
+                .optional("max", self_.max.map(|v| v.to_string()))
+                .attr("step", self_.step_attribute())
+                .hints(&self_.hints)
+                .state(field)
+                .extra(&field.attributes)
+                .finish(html! {}),
+        )
+    }
+}
+
+/// Renders a date or time input with its value and constraints already formatted.
+fn temporal_control<T: Descriptor>(
+    field: &FormField<T>,
+    input_type: &str,
+    value: Option<String>,
+    [min, max]: [Option<String>; 2],
+    step: Option<u32>,
+) -> Markup {
+    let theme = field.theme();
+    wrap(
+        theme.control,
+        Element::new("input")
+            .attr("type", input_type)
+            .attr("id", field.id())
+            .optional("class", input_class(field, theme))
+            .attr("name", field.field_name)
+            .optional("value", value)
+            .optional("min", min)
+            .optional("max", max)
+            .optional("step", step)
+            .state(field)
+            .extra(&field.attributes)
+            .finish(html! {}),
+    )
+}
+
+impl<T: CivilDate> RenderParts<DatePicker<T>> for Maud {
+    fn label(field: &FormField<DatePicker<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<DatePicker<T>>) -> Markup {
+        let DatePicker { min, max } = &field.descriptor;
+        temporal_control(
+            field,
+            "date",
+            field.intermediate.map(civil::format_date),
+            [min.as_ref().map(civil::format_date), max.as_ref().map(civil::format_date)],
+            None,
+        )
+    }
+}
+
+impl<T: CivilTime> RenderParts<TimePicker<T>> for Maud {
+    fn label(field: &FormField<TimePicker<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<TimePicker<T>>) -> Markup {
+        let TimePicker { min, max, step } = &field.descriptor;
+        temporal_control(
+            field,
+            "time",
+            field.intermediate.map(civil::format_time),
+            [min.as_ref().map(civil::format_time), max.as_ref().map(civil::format_time)],
+            *step,
+        )
+    }
+}
+
+impl<T: CivilDateTime> RenderParts<DateTimePicker<T>> for Maud {
+    fn label(field: &FormField<DateTimePicker<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<DateTimePicker<T>>) -> Markup {
+        let DateTimePicker { min, max, step } = &field.descriptor;
+        temporal_control(
+            field,
+            "datetime-local",
+            field.intermediate.map(civil::format_datetime),
+            [min.as_ref().map(civil::format_datetime), max.as_ref().map(civil::format_datetime)],
+            *step,
+        )
+    }
+}
+
+impl<T: CivilDate> RenderParts<MonthPicker<T>> for Maud {
+    fn label(field: &FormField<MonthPicker<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<MonthPicker<T>>) -> Markup {
+        let MonthPicker { min, max, step } = &field.descriptor;
+        temporal_control(
+            field,
+            "month",
+            field.intermediate.map(civil::format_month),
+            [min.as_ref().map(civil::format_month), max.as_ref().map(civil::format_month)],
+            *step,
+        )
+    }
+}
+
+impl<T: CivilDate> RenderParts<WeekPicker<T>> for Maud {
+    fn label(field: &FormField<WeekPicker<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<WeekPicker<T>>) -> Markup {
+        let WeekPicker { min, max, step } = &field.descriptor;
+        temporal_control(
+            field,
+            "week",
+            field.intermediate.map(civil::format_week),
+            [min.as_ref().map(civil::format_week), max.as_ref().map(civil::format_week)],
+            *step,
+        )
+    }
+}
+
+// NOTE: required for input type checkbox means that it has to be true. This isn't desireable.
+impl RenderParts<Checkbox> for Maud {
+    const INLINE: bool = true;
+
+    fn label(field: &FormField<Checkbox>) -> Option<Markup> {
+        Some(html! { (field.display_name) })
+    }
+
+    fn control(field: &FormField<Checkbox>) -> Markup {
+        let Checkbox {
+            required_true,
+            checked,
+        } = &field.descriptor;
+        let prechecked = field.intermediate.unwrap_or(*checked);
+        let theme = field.theme();
+        Element::new("input")
+            .attr("type", "checkbox")
+            .attr("id", field.id())
+            .optional("class", classes(&[theme.checkbox_input, field.attributes.class()]))
+            .attr("name", field.field_name)
+            .attr("value", "true")
+            .flag("checked", prechecked)
+            .flag("required", *required_true)
+            .flag("autofocus", field.autofocus)
+            .optional("aria-invalid", aria_invalid(field))
+            .optional("aria-describedby", aria_describedby(field))
+            .extra(&field.attributes)
+            .finish(html! {})
+    }
+}
+
+impl<T: Selectable + Debug> RenderParts<RadioButton<T>> for Maud {
+    fn control(field: &FormField<RadioButton<T>>) -> Markup {
+        let RadioButton {
+            options,
+            default_value,
+        } = &field.descriptor;
+        let default = default_value.key();
+        let selected = field.intermediate.as_ref().unwrap_or(&default);
+        let focused = first_enabled(options);
+        let theme = field.theme();
+        html! {
+            fieldset class=[classes(&[theme.fieldset])] {
+                legend class=[classes(&[theme.legend])] { (field.display_name) }
+                @for (index, option) in options.iter().enumerate() {
+                    @let key = option.key();
+                    @let display_value = option.display_value();
+                    @let attributes = option.attributes();
+                    @let description = option.description();
+                    @let description_id = description.as_ref().map(|_| description_id(field, index));
+                    (wrap(theme.check, html! {
+                        label class=[classes(&[theme.radio_label])] {
+                            (Element::new("input")
+                                .attr("type", "radio")
+                                .attr("id", option_id(field, index))
+                                .optional("class", classes(&[theme.radio_input, field.attributes.class(), attributes.class()]))
+                                .attr("name", field.field_name)
+                                .attr("value", key.to_string())
+                                .flag("checked", selected == &key && !option.disabled())
+                                .flag("disabled", option.disabled())
+                                .flag("required", field.required)
+                                .flag("autofocus", field.autofocus && Some(index) == focused)
+                                .optional("aria-invalid", aria_invalid(field))
+                                .optional("aria-describedby", option_describedby(field, description_id.as_deref()))
+                                .extra(&attributes)
+                                .extra(&field.attributes)
+                                .finish(html! {}))
+                            (display_value)
+                            (render_description(theme, description_id.as_deref(), description))
+                        }
+                    }))
+                }
+            }
+        }
+    }
+}
+
+/// The index of the first option that can be chosen, which gets the autofocus of the field.
+fn first_enabled<T: Selectable>(options: &[T]) -> Option<usize> {
+    options.iter().position(|option| !option.disabled())
+}
+
+/// Renders the options of a select, in `<optgroup>`s for options with a group.
+/// Disabled options are never selected, as browsers don't submit them.
+fn render_options<T: Selectable>(options: &[T], selected: impl Fn(&T::Key) -> bool) -> Markup {
+    let render_option = |option: &T| {
+        let key = option.key();
+        let attributes = option.attributes();
+        Element::new("option")
+            .attr("value", key.to_string())
+            .optional("class", classes(&[attributes.class()]))
+            .flag("selected", selected(&key) && !option.disabled())
+            .flag("disabled", option.disabled())
+            .extra(&attributes)
+            .finish(html! { (option.display_value()) })
+    };
+    html! {
+        @for (group, options) in group_by(&options.iter().collect::<Vec<_>>(), |option| option.group()) {
+            @if let Some(group) = group {
+                optgroup label=(group) {
+                    @for option in options { (render_option(option)) }
+                }
+            } @else {
+                @for option in options { (render_option(option)) }
+            }
+        }
+    }
+}
+
+impl<T: Selectable + Debug> RenderParts<Select<T>> for Maud {
+    fn label(field: &FormField<Select<T>>) -> Option<Markup> {
+        Some(render_label(field, field.theme()))
+    }
+
+    fn control(field: &FormField<Select<T>>) -> Markup {
+        let Select {
+            default_value,
+            options,
+            placeholder,
+        } = &field.descriptor;
+        let default = default_value.as_ref().map(|v| v.key());
+        let selected = field.intermediate.as_ref().or(default.as_ref());
+        let has_value = field.intermediate.is_some();
+        let theme = field.theme();
+        let invalid = if field.error.is_some() {
+            theme.select_invalid
+        } else {
+            ""
+        };
+        let select = Element::new("select")
+            .attr("id", field.id())
+            .optional(
+                "class",
+                classes(&[theme.select, invalid, field.attributes.class()]),
+            )
+            .attr("name", field.field_name)
+            .state(field)
+            .extra(&field.attributes)
+            .finish(html! {
+                option value="" disabled[field.required] selected[!has_value] { (placeholder) }
+                (render_options(options, |key| selected == Some(key)))
+            });
+        wrap_select(field, theme, false, select)
+    }
+}
+
+impl<T: Selectable + Debug> RenderParts<MultiSelect<T>> for Maud {
+    fn label(field: &FormField<MultiSelect<T>>) -> Option<Markup> {
+        match field.descriptor.mode {
+            MultiSelectMode::Select { .. } => Some(render_label(field, field.theme())),
+            MultiSelectMode::Checkboxes | MultiSelectMode::Switches => None,
+        }
+    }
+
+    fn control(field: &FormField<MultiSelect<T>>) -> Markup {
+        let theme = field.theme();
+        match field.descriptor.mode {
+            MultiSelectMode::Checkboxes => {
+                render_checkboxes(field, theme.check, theme.checkbox_input, None)
+            }
+            MultiSelectMode::Switches => {
+                render_checkboxes(field, theme.switch, theme.switch_input, Some("switch"))
+            }
+            MultiSelectMode::Select { size } => {
+                let keys = &field.intermediate;
+                let invalid = if field.error.is_some() {
+                    theme.select_invalid
+                } else {
+                    ""
+                };
+                let select = Element::new("select")
+                    .attr("id", field.id())
+                    .optional(
+                        "class",
+                        classes(&[theme.select, invalid, field.attributes.class()]),
+                    )
+                    .attr("name", field.field_name)
+                    .flag("multiple", true)
+                    .optional("size", size)
+                    .flag("autofocus", field.autofocus)
+                    .optional("aria-invalid", aria_invalid(field))
+                    .optional("aria-describedby", aria_describedby(field))
+                    .extra(&field.attributes)
+                    .finish(render_options(&field.descriptor.options, |key| {
+                        keys.contains(key)
+                    }));
+                wrap_select(field, theme, true, select)
+            }
+        }
+    }
+}
+
+/// Renders a fieldset with a checkbox per option.
+fn render_checkboxes<T: Selectable + Debug>(
+    field: &FormField<MultiSelect<T>>,
+    check: &str,
+    input: &str,
+    role: Option<&str>,
+) -> Markup {
+    let keys = &field.intermediate;
+    let theme = field.theme();
+    let focused = first_enabled(&field.descriptor.options);
+    html! {
+        fieldset class=[classes(&[theme.fieldset])] {
+            legend class=[classes(&[theme.legend])] { (field.display_name) }
+            @for (index, option) in field.descriptor.options.iter().enumerate() {
+                @let key = option.key();
+                @let selected = keys.contains(&key);
+                @let display_value = option.display_value();
+                @let attributes = option.attributes();
+                @let description = option.description();
+                @let description_id = description.as_ref().map(|_| description_id(field, index));
+                (wrap(check, html! {
+                    label class=[classes(&[theme.checkbox_label])] {
+                        (Element::new("input")
+                            .attr("type", "checkbox")
+                            .optional("role", role)
+                            .attr("id", option_id(field, index))
+                            .optional("class", classes(&[input, field.attributes.class(), attributes.class()]))
+                            .attr("name", field.field_name)
+                            .flag("checked", selected && !option.disabled())
+                            .attr("value", key.to_string())
+                            .flag("disabled", option.disabled())
+                            .flag("autofocus", field.autofocus && Some(index) == focused)
+                            .optional("aria-invalid", aria_invalid(field))
+                            .optional("aria-describedby", option_describedby(field, description_id.as_deref()))
+                            .extra(&attributes)
+                            .extra(&field.attributes)
+                            .finish(html! {}))
+                        (display_value)
+                        (render_description(theme, description_id.as_deref(), description))
+                    }
+                }))
+            }
+        }
+    }
+}
+
+impl RenderParts<Passthrough<String>> for Maud {
+    fn control(field: &FormField<Passthrough<String>>) -> Markup {
+        html! {
+            @if let Some(value) = &field.intermediate {
+                input type="hidden" name=(field.field_name) value=(value);
+            }
+        }
+    }
+}
+
+impl RenderParts<Passthrough<Vec<String>>> for Maud {
+    fn control(field: &FormField<Passthrough<Vec<String>>>) -> Markup {
+        html! {
+            @for value in &field.intermediate {
+                input type="hidden" name=(field.field_name) value=(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as form_fields;
+    use crate::elements::{NumberLocale, number_field::Step};
+    use form_fields_macro::FromForm;
+
+    #[test]
+    fn render_themed() {
+        let mut field = FormField {
+            error: Some("Value is required".to_string()),
+            theme: Some(&Theme::BOOTSTRAP5),
+            ..FormField::new("Name", "name", TextField::default())
+        };
+        assert_eq!(
+            maud::Render::render(&field).into_string(),
+            "<div class=\"mb-3\">\
+            <label for=\"name\" class=\"form-label\">Name</label>\
+            <input type=\"text\" id=\"name\" class=\"form-control is-invalid\" name=\"name\" \
+            aria-invalid=\"true\" aria-describedby=\"name-error\"></input>\
+            <div id=\"name-error\" class=\"invalid-feedback d-block\">Value is required</div>\
+            </div>"
+        );
+
+        field.theme = Some(&Theme::BULMA);
+        field.error = None;
+        assert_eq!(
+            maud::Render::render(&field).into_string(),
+            "<div class=\"field\">\
+            <label for=\"name\" class=\"label\">Name</label>\
+            <div class=\"control\"><input type=\"text\" id=\"name\" class=\"input\" name=\"name\"></input></div>\
+            </div>"
+        );
+    }
+
+    #[derive(FromForm)]
+    #[form(action = "/signup", csrf = "csrf_token", submit = "Sign up")]
+    #[allow(dead_code)]
+    struct SignUp {
+        #[text_field(display_name = "Name")]
+        name: String,
+        #[passthrough]
+        next: Option<String>,
+    }
+
+    #[test]
+    fn render_form() {
+        let mut form = SignUpFormSpec::new();
+        form.meta.set_csrf("csrf_token", "token");
+        form.next.intermediate = Some("/home".to_string());
+        assert_eq!(
+            maud::Render::render(&form).into_string(),
+            "<form method=\"POST\" action=\"/signup\">\
+            <input type=\"hidden\" name=\"csrf_token\" value=\"token\">\
+            <input type=\"hidden\" name=\"next\" value=\"/home\">\
+            <div class=\"form-group\">\
+            <label for=\"sign-up-name\">Name</label>\
+            <input type=\"text\" id=\"sign-up-name\" name=\"name\" required></input>\
+            </div>\
+            <button type=\"submit\">Sign up</button>\
+            </form>"
+        );
+
+        assert!(form_fields::from_form::FormSpec::parse_field(&mut form, "csrf_token", "posted"));
+        assert_eq!(form.meta.csrf_token(), Some("token"));
+        assert!(!form.meta.verify_csrf());
+    }
+
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Address {
+        #[text_field(display_name = "Note")]
+        #[form(order = 1)]
+        note: String,
+        #[text_field(display_name = "City")]
+        #[form(section = "Address", row = 1)]
+        city: String,
+        #[text_field(display_name = "Zip")]
+        #[form(section = "Address", row = 1)]
+        zip: String,
+        #[passthrough]
+        #[form(section = "Address", row = 1)]
+        next: Option<String>,
+        #[text_field(display_name = "Name")]
+        #[form(order = -1)]
+        name: String,
+    }
+
+    #[test]
+    fn render_form_layout() {
+        let mut form = AddressFormSpec::new();
+        form.meta.submit = None;
+        form.next.intermediate = Some("/cart".to_string());
+        assert_eq!(
+            maud::Render::render(&form).into_string(),
+            "<form method=\"POST\">\
+            <input type=\"hidden\" name=\"next\" value=\"/cart\">\
+            <div class=\"form-group\">\
+            <label for=\"address-name\">Name</label>\
+            <input type=\"text\" id=\"address-name\" name=\"name\" required></input>\
+            </div>\
+            <fieldset><legend>Address</legend>\
+            <div class=\"form-row\">\
+            <div class=\"form-group\">\
+            <label for=\"address-city\">City</label>\
+            <input type=\"text\" id=\"address-city\" name=\"city\" required></input>\
+            </div>\
+            <div class=\"form-group\">\
+            <label for=\"address-zip\">Zip</label>\
+            <input type=\"text\" id=\"address-zip\" name=\"zip\" required></input>\
+            </div>\
+            </div>\
+            </fieldset>\
+            <div class=\"form-group\">\
+            <label for=\"address-note\">Note</label>\
+            <input type=\"text\" id=\"address-note\" name=\"note\" required></input>\
+            </div>\
+            </form>"
+        );
+    }
+
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Search {
+        #[text_field(display_name = "Query", attrs(class = "wide", hx_get = "/search", data_controller = "mask"))]
+        query: String,
+        #[checkbox(display_name = "Exact")]
+        exact: bool,
+    }
+
+    #[test]
+    fn render_attributes() {
+        let mut form = SearchFormSpec::new();
+        form.query.add_class("dark");
+        form.query.set_attribute("hx-trigger", "keyup");
+        form.query.set_attribute("name", "other");
+        form.query.set_attribute("REQUIRED", "false");
+        form.query.set_attribute("aria-describedby", "hint");
+        form.query.descriptor.max_length = Some(10);
+        form.query.set_attribute("maxlength", "99");
+        assert_eq!(
+            form.query.render_with::<Maud>().into_string(),
+            "<label for=\"search-query\">Query</label>\
+            <input type=\"text\" id=\"search-query\" class=\"wide dark\" name=\"query\" maxlength=\"10\" required \
+            data-controller=\"mask\" hx-get=\"/search\" hx-trigger=\"keyup\" aria-describedby=\"hint\"></input>"
+        );
+
+        form.exact.set_attribute("x-on:change", "toggle(\"exact\")");
+        form.exact.set_attribute("checked", "");
+        assert_eq!(
+            form.exact.render_with::<Maud>().into_string(),
+            "<label>\
+            <input type=\"checkbox\" id=\"search-exact\" name=\"exact\" value=\"true\" \
+            x-on:change=\"toggle(&quot;exact&quot;)\"></input>\
+            Exact</label>"
+        );
+    }
+
+    /// Renders the error above the input and the label after it.
+    #[derive(Debug)]
+    struct ErrorFirst;
+
+    impl FieldLayout for ErrorFirst {
+        fn render(&self, parts: FieldParts) -> Markup {
+            html! {
+                div class="floating" {
+                    @if let Some(error) = &parts.error {
+                        (error)
+                    }
+                    (parts.control)
+                    @if let Some(label) = &parts.label {
+                        (label)
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn render_layout() {
+        let mut form = SearchFormSpec::new();
+        form.set_layout(&ErrorFirst);
+        form.query.attributes = Default::default();
+        form.query.set_error("Value is required".to_string());
+        assert_eq!(
+            maud::Render::render(&form.query).into_string(),
+            "<div class=\"floating\">\
+            <div id=\"search-query-error\" class=\"error\">Value is required</div>\
+            <input type=\"text\" id=\"search-query\" name=\"query\" required \
+            aria-invalid=\"true\" aria-describedby=\"search-query-error\"></input>\
+            <label for=\"search-query\">Query</label>\
+            </div>"
+        );
+
+        form.exact.layout = None;
+        assert_eq!(
+            maud::Render::render(&form.exact).into_string(),
+            "<div class=\"form-group\"><label>\
+            <input type=\"checkbox\" id=\"search-exact\" name=\"exact\" value=\"true\"></input>\
+            Exact</label></div>"
+        );
+    }
+
+    #[test]
+    fn render_error_summary() {
+        let mut form = SignUpFormSpec::new();
+        assert_eq!(maud::Render::render(&form.error_summary()).into_string(), "");
+
+        form.meta.add_error("Too many attempts");
+        form.name.set_error("Value is required".to_string());
+        form.next.set_error("Invalid value".to_string());
+        assert_eq!(
+            maud::Render::render(&form.error_summary()).into_string(),
+            "<div class=\"error-summary\" role=\"alert\">\
+            <h2>3 problems</h2>\
+            <ul>\
+            <li>Too many attempts</li>\
+            <li><a href=\"#sign-up-name\">Name: Value is required</a></li>\
+            <li>Invalid value</li>\
+            </ul>\
+            </div>"
+        );
+    }
+
+    #[test]
+    fn render_textarea() {
+        let mut textarea = TextArea::<String>::default();
+        textarea.rows = Some(5);
+        textarea.max_length = Some(500);
+        textarea.wrap = Some("hard".to_string());
+        let mut field = FormField {
+            required: true,
+            ..FormField::new("Body", "body", textarea)
+        };
+        field.descriptor.parse("\r\nHello\r\n<b>", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"body\">Body</label>\
+            <textarea id=\"body\" name=\"body\" rows=\"5\" maxlength=\"500\" wrap=\"hard\" required>\
+            \n\nHello\n&lt;b&gt;</textarea>"
+        );
+    }
+
+    #[test]
+    fn render_email() {
+        let mut field = FormField::new("Copy to", "cc", EmailField::<Vec<String>>::default());
+        field.intermediate = field
+            .descriptor
+            .load(vec!["a@example.com".to_string(), "b@example.com".to_string()]);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"cc\">Copy to</label>\
+            <input type=\"email\" id=\"cc\" name=\"cc\" value=\"a@example.com,b@example.com\" multiple></input>"
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn render_url() {
+        let mut field = FormField {
+            required: true,
+            ..FormField::new(
+                "Endpoint",
+                "endpoint",
+                crate::elements::UrlField {
+                    schemes: vec!["https".to_string()],
+                    ..Default::default()
+                },
+            )
+        };
+        field.descriptor.parse("javascript:alert(1)", &mut field.intermediate);
+        field.error = field.descriptor.validate(&field.intermediate).err().map(str::to_string);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"endpoint\">Endpoint</label>\
+            <input type=\"url\" id=\"endpoint\" name=\"endpoint\" value=\"javascript:alert(1)\" required \
+            aria-invalid=\"true\" aria-describedby=\"endpoint-error\"></input>"
+        );
+    }
+
+    #[test]
+    fn render_number() {
+        let mut field = FormField {
+            required: true,
+            ..FormField::new(
+                "Weight",
+                "weight",
+                NumberField {
+                    min: Some(0.5),
+                    max: Some(100.0),
+                    step: Some(Step::Value(0.25)),
+                    locale: None,
+                    hints: InputHints::default(),
+                },
+            )
+        };
+        field.descriptor.parse("2.75", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"weight\">Weight</label>\
+            <input type=\"number\" id=\"weight\" name=\"weight\" value=\"2.75\" min=\"0.5\" \
+            max=\"100\" step=\"0.25\" required></input>"
+        );
+
+        let field = FormField::new(
+            "Offset",
+            "offset",
+            NumberField {
+                min: Some(-10),
+                max: None,
+                step: Some(Step::Any),
+                locale: None,
+                hints: InputHints::default(),
+            },
+        );
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"offset\">Offset</label>\
+            <input type=\"number\" id=\"offset\" name=\"offset\" min=\"-10\" step=\"any\"></input>"
+        );
+    }
+
+    #[test]
+    fn render_number_locale() {
+        let mut field = FormField::new(
+            "Amount",
+            "amount",
+            NumberField {
+                min: Some(0.0),
+                max: None,
+                step: None,
+                locale: Some(NumberLocale::DE),
+                hints: InputHints::default(),
+            },
+        );
+        field.descriptor.parse("1.234,5", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"amount\">Amount</label>\
+            <input type=\"text\" id=\"amount\" name=\"amount\" value=\"1.234,5\" inputmode=\"decimal\"></input>"
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn render_decimal() {
+        use crate::elements::{DecimalField, decimal_field::Decimal};
+
+        let mut field = FormField::new(
+            "Price",
+            "price",
+            DecimalField {
+                min: Some(Decimal::new(5, 1)),
+                max: Some(Decimal::new(999999, 2)),
+                step: Some(Decimal::new(5, 2)),
+                ..Default::default()
+            },
+        );
+        field.descriptor.parse("19.95", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"price\">Price</label>\
+            <input type=\"number\" id=\"price\" name=\"price\" value=\"19.95\" min=\"0.5\" \
+            max=\"9999.99\" step=\"0.05\"></input>"
+        );
+
+        field.descriptor.step = None;
+        assert!(field.render_with::<Maud>().into_string().contains("step=\"any\""));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn render_time_and_week() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let mut field = FormField::new(
+            "Start",
+            "start",
+            TimePicker {
+                min: NaiveTime::from_hms_opt(8, 0, 0),
+                max: NaiveTime::from_hms_opt(18, 0, 0),
+                step: Some(900),
+            },
+        );
+        field.descriptor.parse("09:15:00", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"start\">Start</label>\
+            <input type=\"time\" id=\"start\" name=\"start\" value=\"09:15\" min=\"08:00\" \
+            max=\"18:00\" step=\"900\"></input>"
+        );
+
+        let mut field = FormField::new(
+            "Week",
+            "week",
+            WeekPicker {
+                min: None,
+                max: NaiveDate::from_ymd_opt(2024, 12, 23),
+                step: None,
+            },
+        );
+        field.descriptor.parse("2024-W10", &mut field.intermediate);
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"week\">Week</label>\
+            <input type=\"week\" id=\"week\" name=\"week\" value=\"2024-W10\" max=\"2024-W52\"></input>"
+        );
+    }
+
+    #[derive(FromForm)]
+    #[allow(dead_code)]
+    struct Measurement {
+        #[number_field(display_name = "Offset", min = -10, step = "any")]
+        offset: Option<i32>,
+        #[number_field(display_name = "Serial", max = 340282366920938463463374607431768211455)]
+        serial: Option<u128>,
+        #[textarea(display_name = "Notes", rows = 5, wrap = "hard")]
+        notes: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn render_derived_descriptors() {
+        let form = MeasurementFormSpec::new();
+        assert_eq!(form.offset.descriptor.min, Some(-10));
+        assert_eq!(form.offset.descriptor.step, Some(Step::Any));
+        assert_eq!(form.serial.descriptor.max, Some(u128::MAX));
+        assert_eq!(form.notes.descriptor.rows, Some(5));
+        assert_eq!(form.notes.descriptor.wrap.as_deref(), Some("hard"));
+    }
+}
@@ -0,0 +1,33 @@
+pub mod attributes;
+pub mod context;
+#[cfg(feature = "maud")]
+pub mod maud;
+pub mod theme;
+
+use crate::{Descriptor, FormField};
+
+/// A layout arranging the rendered parts of a field.
+/// Layouts require the `maud` feature.
+#[cfg(feature = "maud")]
+pub type Layout = &'static dyn maud::FieldLayout;
+#[cfg(not(feature = "maud"))]
+pub type Layout = std::convert::Infallible;
+
+/// A backend that turns a form field into markup.
+/// Rendering is implemented per backend and per descriptor, so parsing and validation
+/// work without any HTML engine compiled in.
+pub trait Renderer<D: Descriptor> {
+    type Output;
+
+    /// Renders the form field.
+    /// Preserves previous input values, even if they are invalid.
+    /// Should not try to render error or help messages.
+    fn render(field: &FormField<D>) -> Self::Output;
+}
+
+impl<T: Descriptor> FormField<T> {
+    /// Renders the form field with the given backend.
+    pub fn render_with<R: Renderer<T>>(&self) -> R::Output {
+        R::render(self)
+    }
+}
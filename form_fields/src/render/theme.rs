@@ -0,0 +1,219 @@
+use std::sync::RwLock;
+
+/// CSS classes used when rendering form fields.
+/// Empty strings omit the class, or the wrapping element for wrappers.
+///
+/// A theme can be chosen per form field, per form with the generated `set_theme`
+/// or globally with [`Theme::set_default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Wrapper around each field, including help text and error.
+    pub group: &'static str,
+    pub label: &'static str,
+    /// Wrapper around the input element.
+    pub control: &'static str,
+    pub input: &'static str,
+    pub input_invalid: &'static str,
+    pub textarea: &'static str,
+    /// Wrapper around the select element.
+    pub select_wrapper: &'static str,
+    /// Added to the select wrapper of an invalid field.
+    pub select_wrapper_invalid: &'static str,
+    /// Added to the select wrapper of a `<select multiple>`.
+    pub select_wrapper_multiple: &'static str,
+    pub select: &'static str,
+    pub select_invalid: &'static str,
+    /// Wrapper around a single checkbox or radio button and its label.
+    pub check: &'static str,
+    pub checkbox_label: &'static str,
+    pub checkbox_input: &'static str,
+    /// Wrapper around a single checkbox rendered as a switch and its label.
+    pub switch: &'static str,
+    pub switch_input: &'static str,
+    pub radio_label: &'static str,
+    pub radio_input: &'static str,
+    pub fieldset: &'static str,
+    pub legend: &'static str,
+    /// Wrapper around fields rendered side by side.
+    pub row: &'static str,
+    /// Wrapper around each field of a row.
+    pub column: &'static str,
+    pub help: &'static str,
+    pub error: &'static str,
+    /// The submit button of a form.
+    pub button: &'static str,
+    /// Wrapper around the error summary of a form.
+    pub error_summary: &'static str,
+    /// Renders help and error messages as `<small>` instead of `<div>`.
+    pub small_feedback: bool,
+}
+
+static DEFAULT_THEME: RwLock<&'static Theme> = RwLock::new(&Theme::PLAIN);
+
+impl Theme {
+    /// The classes used before themes existed. Matches no CSS framework.
+    pub const PLAIN: Theme = Theme {
+        group: "form-group",
+        label: "",
+        control: "",
+        input: "",
+        input_invalid: "",
+        textarea: "",
+        select_wrapper: "",
+        select_wrapper_invalid: "",
+        select_wrapper_multiple: "",
+        select: "",
+        select_invalid: "",
+        check: "",
+        checkbox_label: "",
+        checkbox_input: "",
+        switch: "",
+        switch_input: "",
+        radio_label: "",
+        radio_input: "",
+        fieldset: "",
+        legend: "",
+        row: "form-row",
+        column: "",
+        help: "help-text",
+        error: "error",
+        button: "",
+        error_summary: "error-summary",
+        small_feedback: false,
+    };
+
+    /// [Bootstrap 5](https://getbootstrap.com/docs/5.3/forms/overview/)
+    pub const BOOTSTRAP5: Theme = Theme {
+        group: "mb-3",
+        label: "form-label",
+        control: "",
+        input: "form-control",
+        input_invalid: "is-invalid",
+        textarea: "form-control",
+        select_wrapper: "",
+        select_wrapper_invalid: "",
+        select_wrapper_multiple: "",
+        select: "form-select",
+        select_invalid: "is-invalid",
+        check: "form-check",
+        checkbox_label: "form-check-label",
+        checkbox_input: "form-check-input",
+        switch: "form-check form-switch",
+        switch_input: "form-check-input",
+        radio_label: "form-check-label",
+        radio_input: "form-check-input",
+        fieldset: "mb-3",
+        legend: "form-label",
+        row: "row",
+        column: "col",
+        help: "form-text",
+        error: "invalid-feedback d-block",
+        button: "btn btn-primary",
+        error_summary: "alert alert-danger",
+        small_feedback: false,
+    };
+
+    /// [Bulma](https://bulma.io/documentation/form/general/)
+    pub const BULMA: Theme = Theme {
+        group: "field",
+        label: "label",
+        control: "control",
+        input: "input",
+        input_invalid: "is-danger",
+        textarea: "textarea",
+        select_wrapper: "select",
+        select_wrapper_invalid: "is-danger",
+        select_wrapper_multiple: "is-multiple",
+        select: "",
+        select_invalid: "",
+        check: "control",
+        checkbox_label: "checkbox",
+        checkbox_input: "",
+        switch: "control",
+        switch_input: "",
+        radio_label: "radio",
+        radio_input: "",
+        fieldset: "field",
+        legend: "label",
+        row: "columns",
+        column: "column",
+        help: "help",
+        error: "help is-danger",
+        button: "button is-primary",
+        error_summary: "notification is-danger",
+        small_feedback: false,
+    };
+
+    /// [Tailwind](https://tailwindcss.com/) with [daisyUI](https://daisyui.com/)
+    pub const DAISYUI: Theme = Theme {
+        group: "form-control w-full",
+        label: "label label-text",
+        control: "",
+        input: "input input-bordered w-full",
+        input_invalid: "input-error",
+        textarea: "textarea textarea-bordered w-full",
+        select_wrapper: "",
+        select_wrapper_invalid: "",
+        select_wrapper_multiple: "",
+        select: "select select-bordered w-full",
+        select_invalid: "select-error",
+        check: "form-control",
+        checkbox_label: "label cursor-pointer justify-start gap-2",
+        checkbox_input: "checkbox",
+        switch: "form-control",
+        switch_input: "toggle",
+        radio_label: "label cursor-pointer justify-start gap-2",
+        radio_input: "radio",
+        fieldset: "form-control w-full",
+        legend: "label label-text",
+        row: "flex gap-4",
+        column: "flex-1",
+        help: "label-text-alt",
+        error: "label-text-alt text-error",
+        button: "btn btn-primary",
+        error_summary: "alert alert-error flex-col items-start",
+        small_feedback: false,
+    };
+
+    /// [Pico](https://picocss.com/docs/forms)
+    /// Mostly classless, marks invalid fields through `aria-invalid`.
+    pub const PICO: Theme = Theme {
+        group: "",
+        label: "",
+        control: "",
+        input: "",
+        input_invalid: "",
+        textarea: "",
+        select_wrapper: "",
+        select_wrapper_invalid: "",
+        select_wrapper_multiple: "",
+        select: "",
+        select_invalid: "",
+        check: "",
+        checkbox_label: "",
+        checkbox_input: "",
+        switch: "",
+        switch_input: "",
+        radio_label: "",
+        radio_input: "",
+        fieldset: "",
+        legend: "",
+        row: "grid",
+        column: "",
+        help: "",
+        error: "",
+        button: "",
+        error_summary: "",
+        small_feedback: true,
+    };
+
+    /// Sets the theme used by every field without an explicit theme.
+    pub fn set_default(theme: &'static Theme) {
+        *DEFAULT_THEME.write().unwrap_or_else(|e| e.into_inner()) = theme;
+    }
+
+    /// Returns the theme used by every field without an explicit theme.
+    pub fn default_theme() -> &'static Theme {
+        *DEFAULT_THEME.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -1,13 +1,67 @@
+use crate::render::attributes::Attributes;
+
 /// A trait for types that can be selected in a form field.
 /// This trait is used for radio buttons, select and multiselect fields.
 /// Splits display value and key.
 pub trait Selectable: Clone {
     type Key: PartialEq + ToString + std::str::FromStr;
-    type DisplayValue: maud::Render;
+    /// The label of the option.
+    type DisplayValue: std::fmt::Display;
     fn key(&self) -> Self::Key;
     fn display_value(&self) -> Self::DisplayValue;
+
+    /// The group of the option, rendered as an `<optgroup>` in select fields.
+    /// Options of the same group are rendered together, at the position of the first one.
+    fn group(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the option is shown but can't be chosen, e.g. when sold out.
+    /// Disabled options are also rejected by validation.
+    fn disabled(&self) -> bool {
+        false
+    }
+
+    /// A secondary line of text, rendered below the label of radio buttons and checkboxes.
+    fn description(&self) -> Option<String> {
+        None
+    }
+
+    /// Extra HTML attributes of the option's `<input>` or `<option>` element.
+    fn attributes(&self) -> Attributes {
+        Attributes::default()
+    }
+}
+
+/// A pair of key and label, e.g. a row loaded from a database.
+impl<K> Selectable for (K, String)
+where
+    K: Clone + PartialEq + ToString + std::str::FromStr,
+{
+    type Key = K;
+    type DisplayValue = String;
+
+    fn key(&self) -> Self::Key {
+        self.0.clone()
+    }
+
+    fn display_value(&self) -> Self::DisplayValue {
+        self.1.clone()
+    }
 }
 
+/// The error of `FromStr` for enums deriving `Selectable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownOption;
+
+impl std::fmt::Display for UnknownOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Unknown option")
+    }
+}
+
+impl std::error::Error for UnknownOption {}
+
 macro_rules! declare_selectable {
     ($type:ty) => {
         impl Selectable for $type {
@@ -39,3 +93,345 @@ declare_selectable!(f32);
 declare_selectable!(f64);
 declare_selectable!(String);
 declare_selectable!(char);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as form_fields;
+    use form_fields_macro::{FromForm, Selectable};
+
+    #[derive(Debug, Clone, PartialEq, Selectable)]
+    #[selectable(rename_all = "snake_case")]
+    enum Fuel {
+        Petrol,
+        #[selectable(label = "Diesel engine")]
+        Diesel,
+        PlugInHybrid,
+        #[selectable(key = "ev", label = "Electric")]
+        BatteryElectric,
+    }
+
+    #[test]
+    fn derive_enum() {
+        assert_eq!(
+            Fuel::ALL.iter().map(Fuel::to_string).collect::<Vec<_>>(),
+            ["petrol", "diesel", "plug_in_hybrid", "ev"]
+        );
+        assert_eq!(
+            Fuel::ALL.iter().map(Fuel::display_value).collect::<Vec<_>>(),
+            ["Petrol", "Diesel engine", "PlugInHybrid", "Electric"]
+        );
+        assert_eq!("ev".parse(), Ok(Fuel::BatteryElectric));
+        assert_eq!("Diesel".parse::<Fuel>(), Err(UnknownOption));
+    }
+
+    #[derive(FromForm)]
+    struct Car {
+        #[select(display_name = "Fuel", options = all, placeholder = "--")]
+        fuel: Fuel,
+    }
+
+    #[test]
+    fn options_all() {
+        let mut form = CarFormSpec::new();
+        assert_eq!(form.fuel.descriptor.options, Fuel::ALL);
+        form_fields::from_form::FormSpec::parse_field(&mut form, "fuel", "plug_in_hybrid");
+        assert_eq!(form.inner().unwrap().fuel, Fuel::PlugInHybrid);
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_label() {
+        assert_eq!(
+            maud::html! { (Fuel::BatteryElectric) }.into_string(),
+            "Electric"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, Selectable)]
+    struct Category {
+        #[selectable(key)]
+        id: i64,
+        #[selectable(label)]
+        name: String,
+        parent: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Selectable)]
+    struct Tag(#[selectable(key)] String);
+
+    #[test]
+    fn derive_struct() {
+        let category = Category {
+            id: 7,
+            name: "Books".to_string(),
+            parent: None,
+        };
+        assert_eq!(category.key(), 7);
+        assert_eq!(category.display_value(), "Books");
+
+        let tag = Tag("rust".to_string());
+        assert_eq!(tag.key(), "rust");
+        assert_eq!(tag.display_value(), "rust");
+
+        let pair = (3u8, "Three".to_string());
+        assert_eq!(pair.key(), 3);
+        assert_eq!(pair.display_value(), "Three");
+    }
+
+    #[derive(Debug, Clone)]
+    struct Seat {
+        row: &'static str,
+        sold_out: bool,
+    }
+
+    impl Selectable for Seat {
+        type Key = String;
+        type DisplayValue = String;
+
+        fn key(&self) -> String {
+            self.row.to_lowercase()
+        }
+
+        fn display_value(&self) -> String {
+            format!("Row {}", self.row)
+        }
+
+        fn disabled(&self) -> bool {
+            self.sold_out
+        }
+
+        fn description(&self) -> Option<String> {
+            self.sold_out.then(|| "Sold out".to_string())
+        }
+
+        fn attributes(&self) -> Attributes {
+            let mut attributes = Attributes::default();
+            attributes.set("data-row", self.row);
+            attributes
+        }
+    }
+
+    fn seats() -> Vec<Seat> {
+        vec![
+            Seat {
+                row: "A",
+                sold_out: true,
+            },
+            Seat {
+                row: "B",
+                sold_out: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn validate_disabled() {
+        use crate::{
+            Descriptor,
+            elements::{MultiSelect, RadioButton, Select, multiselect::MultiSelectMode},
+        };
+
+        let radio = RadioButton {
+            options: seats(),
+            default_value: seats().remove(1),
+        };
+        assert_eq!(
+            radio.validate(&Some("a".to_string())).unwrap_err(),
+            "Option is not available"
+        );
+
+        let select = Select {
+            options: seats(),
+            default_value: None,
+            placeholder: "--".to_string(),
+        };
+        assert!(select.validate(&Some("a".to_string())).is_err());
+        assert_eq!(select.validate(&Some("b".to_string())).unwrap().row, "B");
+
+        let multiselect = MultiSelect {
+            options: seats(),
+            mode: MultiSelectMode::Checkboxes,
+        };
+        assert_eq!(
+            multiselect.validate(&vec!["b".to_string(), "a".to_string()]).unwrap_err(),
+            "Option is not available"
+        );
+    }
+
+    #[test]
+    fn render_disabled_default() {
+        use crate::{FormField, elements::RadioButton, render::context::Context};
+
+        let field = FormField::new(
+            "Seat",
+            "seat",
+            RadioButton {
+                options: seats(),
+                default_value: seats().remove(0),
+            },
+        );
+        let context = field.render_with::<Context>();
+        assert_eq!(context.value, None);
+        assert!(context.options.iter().all(|option| !option.selected));
+        #[cfg(feature = "maud")]
+        assert!(
+            !field
+                .render_with::<crate::render::maud::Maud>()
+                .into_string()
+                .contains("checked")
+        );
+    }
+
+    #[test]
+    fn render_disabled_select() {
+        use crate::{FormField, elements::Select, render::context::Context};
+
+        let field = FormField::new(
+            "Seat",
+            "seat",
+            Select {
+                options: seats(),
+                default_value: seats().into_iter().next(),
+                placeholder: "--".to_string(),
+            },
+        );
+        let context = field.render_with::<Context>();
+        assert_eq!(context.value, None);
+        assert!(context.options.iter().all(|option| !option.selected));
+        #[cfg(feature = "maud")]
+        assert!(
+            field
+                .render_with::<crate::render::maud::Maud>()
+                .into_string()
+                .contains("<option value=\"a\" disabled data-row=\"A\">")
+        );
+    }
+
+    #[test]
+    fn render_disabled_multiselect() {
+        use crate::{
+            Descriptor, FormField,
+            elements::{MultiSelect, multiselect::MultiSelectMode},
+            render::context::Context,
+        };
+
+        for mode in [
+            MultiSelectMode::Checkboxes,
+            MultiSelectMode::Select { size: None },
+        ] {
+            let mut field = FormField::new(
+                "Seats",
+                "seats",
+                MultiSelect {
+                    options: seats(),
+                    mode,
+                },
+            );
+            field.intermediate = field.descriptor.load(seats());
+            let context = field.render_with::<Context>();
+            assert_eq!(context.values, vec!["b".to_string()]);
+            let selected = context.options.iter().filter(|option| option.selected);
+            assert_eq!(selected.map(|option| option.value.as_str()).collect::<Vec<_>>(), ["b"]);
+            #[cfg(feature = "maud")]
+            {
+                let markup = field
+                    .render_with::<crate::render::maud::Maud>()
+                    .into_string();
+                assert!(
+                    markup.contains("checked value=\"b\"")
+                        || markup.contains("value=\"b\" selected")
+                );
+                assert!(!markup.contains("checked value=\"a\""));
+                assert!(!markup.contains("value=\"a\" selected"));
+            }
+        }
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_autofocus_enabled() {
+        use crate::{
+            FormField,
+            elements::{MultiSelect, RadioButton, multiselect::MultiSelectMode},
+            render::maud::Maud,
+        };
+
+        let field = FormField {
+            autofocus: true,
+            ..FormField::new(
+                "Seat",
+                "seat",
+                RadioButton {
+                    options: seats(),
+                    default_value: seats().remove(1),
+                },
+            )
+        };
+        let markup = field.render_with::<Maud>().into_string();
+        assert!(markup.contains("id=\"seat-1\" name=\"seat\" value=\"b\" checked autofocus"));
+        assert_eq!(markup.matches("autofocus").count(), 1);
+
+        let field = FormField {
+            autofocus: true,
+            ..FormField::new(
+                "Seats",
+                "seats",
+                MultiSelect {
+                    options: seats(),
+                    mode: MultiSelectMode::Checkboxes,
+                },
+            )
+        };
+        let markup = field.render_with::<Maud>().into_string();
+        assert!(markup.contains("value=\"b\" autofocus"));
+        assert_eq!(markup.matches("autofocus").count(), 1);
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn render_option_metadata() {
+        use crate::{
+            FormField,
+            elements::{RadioButton, Select},
+            render::maud::Maud,
+        };
+
+        let field = FormField::new(
+            "Seat",
+            "seat",
+            RadioButton {
+                options: seats(),
+                default_value: seats().remove(1),
+            },
+        );
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<fieldset><legend>Seat</legend>\
+            <label><input type=\"radio\" id=\"seat\" name=\"seat\" value=\"a\" disabled \
+            aria-describedby=\"seat-description\" data-row=\"A\"></input>Row A\
+            <small id=\"seat-description\" class=\"help-text\">Sold out</small></label>\
+            <label><input type=\"radio\" id=\"seat-1\" name=\"seat\" value=\"b\" checked data-row=\"B\"></input>Row B</label>\
+            </fieldset>"
+        );
+
+        let field = FormField::new(
+            "Seat",
+            "seat",
+            Select {
+                options: seats(),
+                default_value: None,
+                placeholder: "--".to_string(),
+            },
+        );
+        assert_eq!(
+            field.render_with::<Maud>().into_string(),
+            "<label for=\"seat\">Seat</label>\
+            <select id=\"seat\" name=\"seat\">\
+            <option value=\"\" selected>--</option>\
+            <option value=\"a\" disabled data-row=\"A\">Row A</option>\
+            <option value=\"b\" data-row=\"B\">Row B</option>\
+            </select>"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Compile time checks of date and time bounds.
+//!
+//! The bounds are parsed in `const` items by the same functions of
+//! `form_fields::elements::civil` that parse submitted values, so an invalid bound or
+//! a year the date type can't represent fails to compile. The values are then built with
+//! the constructors of the `form_fields::elements::civil` traits, so they work with
+//! whichever date crate the field uses.
+
+use proc_macro2::{Span, TokenStream};
+
+/// The format of a bound and the function of `form_fields::elements::civil` parsing it.
+#[derive(Clone, Copy)]
+pub(crate) enum Format {
+    Date,
+    Time,
+    DateTime,
+    Month,
+    Week,
+}
+
+impl Format {
+    fn pattern(self) -> &'static str {
+        match self {
+            Self::Date => "YYYY-MM-DD",
+            Self::Time => "HH:MM[:SS]",
+            Self::DateTime => "YYYY-MM-DDTHH:MM[:SS]",
+            Self::Month => "YYYY-MM",
+            Self::Week => "YYYY-Www",
+        }
+    }
+
+    fn parts(self) -> TokenStream {
+        match self {
+            Self::Date => quote::quote! { date_parts },
+            Self::Time => quote::quote! { time_parts },
+            Self::DateTime => quote::quote! { datetime_parts },
+            Self::Month => quote::quote! { month_parts },
+            Self::Week => quote::quote! { week_parts },
+        }
+    }
+}
+
+fn quote_date(ty: &TokenStream, date: TokenStream, span: Span) -> TokenStream {
+    let date = quote::quote_spanned! {span=>
+        form_fields::elements::civil::__in_range::<#ty>(#date)
+    };
+    quote::quote! {{
+        const DATE: (i32, u32, u32) = #date;
+        <#ty as form_fields::elements::civil::CivilDate>::from_ymd(DATE.0, DATE.1, DATE.2)
+            .expect("checked at compile time")
+    }}
+}
+
+fn quote_time(ty: &TokenStream, time: TokenStream) -> TokenStream {
+    quote::quote! {{
+        const TIME: (u32, u32, u32, u32) = #time;
+        <#ty as form_fields::elements::civil::CivilTime>::from_hms_nano(TIME.0, TIME.1, TIME.2, TIME.3)
+            .expect("checked at compile time")
+    }}
+}
+
+/// Emits a bound of the field type `ty`, parsed and checked at compile time.
+pub(crate) fn quote_bound(
+    value: Option<syn::LitStr>,
+    format: Format,
+    ty: &syn::Type,
+) -> TokenStream {
+    let Some(value) = value else {
+        return quote::quote! { None };
+    };
+    let message = format!("expected a value formatted as `{}`", format.pattern());
+    let parts = format.parts();
+    let parsed = quote::quote_spanned! {value.span()=>
+        form_fields::elements::civil::__parsed(
+            form_fields::elements::civil::#parts(#value),
+            #message,
+        )
+    };
+    let ty = quote::quote! { #ty };
+    let bound = match format {
+        Format::Date | Format::Month | Format::Week => quote_date(&ty, parsed, value.span()),
+        Format::Time => quote_time(&ty, parsed),
+        Format::DateTime => {
+            let civil_datetime = quote::quote! {
+                <#ty as form_fields::elements::civil::CivilDateTime>
+            };
+            let date = quote_date(
+                &quote::quote! { #civil_datetime::Date },
+                quote::quote! { PARTS.0 },
+                value.span(),
+            );
+            let time = quote_time(
+                &quote::quote! { #civil_datetime::Time },
+                quote::quote! { PARTS.1 },
+            );
+            quote::quote! {{
+                const PARTS: ((i32, u32, u32), (u32, u32, u32, u32)) = #parsed;
+                #civil_datetime::new(#date, #time)
+            }}
+        }
+    };
+    quote::quote! { Some(#bound) }
+}
@@ -1,8 +1,8 @@
-use std::str::FromStr;
-
 use crate::{
-    BaseField, FieldParseResult, maybe_extract_attribute,
-    to_quote::{ToQuote, ToQuoteAs},
+    BaseField, FieldParseResult,
+    civil::{Format, quote_bound},
+    maybe_extract_attribute,
+    to_quote::ToQuote,
 };
 
 // Example #[date_select(min = "2023-01-01", max = "2023-12-31")]
@@ -11,8 +11,8 @@ use crate::{
 struct DatePickerAttributes {
     #[deluxe(flatten)]
     base: BaseField,
-    min: Option<String>,
-    max: Option<String>,
+    min: Option<syn::LitStr>,
+    max: Option<syn::LitStr>,
 }
 
 pub(crate) fn try_parse(
@@ -23,26 +23,22 @@ pub(crate) fn try_parse(
 ) -> deluxe::Result<Option<FieldParseResult>> {
     if let Some(attrs) = maybe_extract_attribute::<_, DatePickerAttributes>(field)? {
         let help_text = attrs.base.help_text.to_quote();
-        let min = attrs
-            .min
-            .and_then(|min| chrono::NaiveDate::from_str(&min).ok());
-        let max = attrs
-            .max
-            .and_then(|min| chrono::NaiveDate::from_str(&min).ok());
+        let min = quote_bound(attrs.min, Format::Date, field_type);
+        let max = quote_bound(attrs.max, Format::Date, field_type);
 
-        let min = min.to_quote_as(field_type);
-        let max = max.to_quote_as(field_type);
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: None,
             field_type: quote::quote! {
-                form_fields::elements::DatePicker
+                form_fields::elements::DatePicker::<#field_type>
             },
             initializer: quote::quote! {
-                form_fields::elements::DatePicker {
+                form_fields::elements::DatePicker::<#field_type> {
                     min: #min,
                     max: #max,
                 }
@@ -0,0 +1,117 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
+};
+
+// Example #[decimal_field(min = 0, max = 999.99, step = 0.01, scale = 2)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(decimal_field))]
+struct DecimalFieldAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+    step: Option<syn::Expr>,
+    scale: Option<u32>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
+}
+
+/// Largest scale supported by `rust_decimal`.
+const MAX_SCALE: usize = 28;
+
+/// Converts a decimal literal like `-12.50` or `"0.01"` into a `Decimal` constructor,
+/// checking at compile time that it fits.
+fn parse_decimal(expr: Option<syn::Expr>) -> deluxe::Result<TokenStream> {
+    let Some(expr) = expr else {
+        return Ok(quote::quote! { None });
+    };
+
+    let (negative, lit) = match &expr {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, expr.as_ref()),
+        expr => (false, expr),
+    };
+    let digits = match lit {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            syn::Lit::Int(int) if int.suffix().is_empty() => Some(int.base10_digits().to_string()),
+            syn::Lit::Float(float) if float.suffix().is_empty() => {
+                Some(float.base10_digits().to_string())
+            }
+            syn::Lit::Str(str) => Some(str.value()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let mantissa = digits.and_then(|digits| {
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(digits) => (!negative, digits.to_string()),
+            None => (negative, digits),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        if integer.is_empty()
+            || fraction.len() > MAX_SCALE
+            || !(integer.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let mantissa = format!("{integer}{fraction}").parse::<i128>().ok()?;
+        // `Decimal` stores a 96 bit mantissa.
+        (mantissa < 1 << 96).then_some((
+            if negative { -mantissa } else { mantissa },
+            fraction.len() as u32,
+        ))
+    });
+    let Some((mantissa, scale)) = mantissa else {
+        return Err(syn::Error::new_spanned(expr, "expected a decimal number"));
+    };
+
+    Ok(quote::quote! {
+        Some(form_fields::elements::decimal_field::Decimal::from_i128_with_scale(#mantissa, #scale))
+    })
+}
+
+pub(crate) fn try_parse(
+    field: &mut syn::Field,
+    ident: &syn::Ident,
+    _field_type: &syn::Type,
+    required: bool,
+) -> deluxe::Result<Option<FieldParseResult>> {
+    if let Some(attrs) = maybe_extract_attribute::<_, DecimalFieldAttributes>(field)? {
+        let help_text = attrs.base.help_text.to_quote();
+        let min = parse_decimal(attrs.min)?;
+        let max = parse_decimal(attrs.max)?;
+        let step = parse_decimal(attrs.step)?;
+        let scale = attrs.scale.to_quote();
+        let hints = attrs.hints.to_quote();
+        Ok(Some(FieldParseResult {
+            ident: ident.clone(),
+            required,
+            attributes: attrs.base.attributes(),
+            display_name: attrs.base.display_name,
+            field_name: attrs.base.field_name,
+            help_text,
+            options_from: None,
+            field_type: quote::quote! {
+                form_fields::__decimal_field!()
+            },
+            initializer: quote::quote! {
+                form_fields::__decimal_field!({
+                    min: #min,
+                    max: #max,
+                    step: #step,
+                    scale: #scale,
+                    hints: #hints,
+                })
+            },
+        }))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
+};
+
+// Example #[email_field(lowercase_domain, punycode)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(email_field))]
+struct EmailFieldAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    max_length: Option<usize>,
+    placeholder: Option<String>,
+    lowercase_domain: Option<bool>,
+    punycode: Option<bool>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
+}
+
+pub(crate) fn try_parse(
+    field: &mut syn::Field,
+    ident: &syn::Ident,
+    field_type: &syn::Type,
+    required: bool,
+) -> deluxe::Result<Option<FieldParseResult>> {
+    if let Some(attrs) = maybe_extract_attribute::<_, EmailFieldAttributes>(field)? {
+        let help_text = attrs.base.help_text.to_quote();
+        let max_length = attrs.max_length.to_quote();
+        let placeholder = attrs
+            .placeholder
+            .map(|placeholder| quote::quote! { #placeholder.to_string() })
+            .to_quote();
+        let lowercase_domain = attrs.lowercase_domain.unwrap_or(false);
+        let punycode = attrs.punycode.unwrap_or(false);
+        let hints = attrs.hints.to_quote();
+        Ok(Some(FieldParseResult {
+            ident: ident.clone(),
+            required,
+            attributes: attrs.base.attributes(),
+            display_name: attrs.base.display_name,
+            field_name: attrs.base.field_name,
+            help_text,
+            options_from: None,
+            field_type: quote::quote! {
+                form_fields::elements::EmailField::<#field_type>
+            },
+            initializer: quote::quote! {
+                {
+                    let mut email_field = form_fields::elements::EmailField::<#field_type>::default();
+                    email_field.placeholder = #placeholder;
+                    email_field.max_length = #max_length;
+                    email_field.lowercase_domain = #lowercase_domain;
+                    form_fields::__punycode!(email_field, #punycode);
+                    email_field.hints = #hints;
+                    email_field
+                }
+            },
+        }))
+    } else {
+        Ok(None)
+    }
+}
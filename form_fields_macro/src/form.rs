@@ -0,0 +1,130 @@
+use crate::maybe_extract_attribute;
+
+// Example #[form(action = "/signup", csrf = "csrf_token", submit = "Sign up")]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(form))]
+struct FormAttributes {
+    #[deluxe(default)]
+    action: Option<String>,
+    #[deluxe(default)]
+    method: Option<syn::LitStr>,
+    #[deluxe(default)]
+    enctype: Option<String>,
+    #[deluxe(default)]
+    csrf: Option<String>,
+    #[deluxe(default)]
+    hidden: Vec<String>,
+    #[deluxe(default)]
+    submit: Option<String>,
+}
+
+/// Generates the initializer of the `meta` field of the form spec.
+pub(crate) fn parse_meta(ast: &mut syn::DeriveInput) -> deluxe::Result<proc_macro2::TokenStream> {
+    let Some(attrs) = maybe_extract_attribute::<_, FormAttributes>(ast)? else {
+        return Ok(quote::quote! { form_fields::meta::FormMeta::default() });
+    };
+
+    let action = attrs
+        .action
+        .map(|action| quote::quote! { meta.action = Some(#action.to_string()); });
+    let method = match attrs.method {
+        Some(method) => {
+            let variant = match method.value().to_uppercase().as_str() {
+                "GET" => quote::quote! { Get },
+                "POST" => quote::quote! { Post },
+                _ => {
+                    return Err(syn::Error::new(
+                        method.span(),
+                        "HTML forms only support \"GET\" and \"POST\"",
+                    ));
+                }
+            };
+            Some(quote::quote! { meta.method = form_fields::meta::FormMethod::#variant; })
+        }
+        None => None,
+    };
+    let enctype = attrs
+        .enctype
+        .map(|enctype| quote::quote! { meta.enctype = Some(#enctype); });
+    let csrf = attrs
+        .csrf
+        .map(|csrf| quote::quote! { meta.set_csrf(#csrf, ""); });
+    let hidden = attrs.hidden;
+    let submit = attrs
+        .submit
+        .map(|label| quote::quote! {
+            meta.submit = Some(form_fields::meta::SubmitButton {
+                label: #label.to_string(),
+                ..Default::default()
+            });
+        });
+
+    Ok(quote::quote! {
+        {
+            let mut meta = form_fields::meta::FormMeta::default();
+            #action
+            #method
+            #enctype
+            #csrf
+            #(
+                meta.set_hidden(#hidden, "");
+            )*
+            #submit
+            meta
+        }
+    })
+}
+
+// Example #[form(section = "Address", row = 1, order = -1)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(form))]
+struct FormFieldAttributes {
+    #[deluxe(default)]
+    section: Option<String>,
+    #[deluxe(default)]
+    row: Option<syn::Lit>,
+    #[deluxe(default)]
+    order: i32,
+}
+
+/// Generates the `FieldPlacement` of a field with a `#[form(...)]` attribute.
+pub(crate) fn parse_placement(
+    field: &mut syn::Field,
+) -> deluxe::Result<Option<proc_macro2::TokenStream>> {
+    let Some(attrs) = maybe_extract_attribute::<_, FormFieldAttributes>(field)? else {
+        return Ok(None);
+    };
+
+    let ident = field.ident.as_ref().unwrap().to_string();
+    let section = match attrs.section {
+        Some(section) => quote::quote! { Some(#section) },
+        None => quote::quote! { None },
+    };
+    let row = match attrs.row {
+        Some(syn::Lit::Str(row)) => {
+            let row = row.value();
+            quote::quote! { Some(#row) }
+        }
+        Some(syn::Lit::Int(row)) => {
+            let row = row.base10_digits().to_string();
+            quote::quote! { Some(#row) }
+        }
+        Some(lit) => {
+            return Err(syn::Error::new(
+                lit.span(),
+                "Expected a string or integer literal",
+            ));
+        }
+        None => quote::quote! { None },
+    };
+    let order = attrs.order;
+
+    Ok(Some(quote::quote! {
+        form_fields::meta::FieldPlacement {
+            field: #ident,
+            section: #section,
+            row: #row,
+            order: #order,
+        }
+    }))
+}
@@ -0,0 +1,61 @@
+use proc_macro2::TokenStream;
+
+use crate::to_quote::ToQuote;
+
+// Example #[text_field(autocomplete = "username", spellcheck = false, size = 20)]
+#[derive(deluxe::ParseMetaItem)]
+pub(crate) struct InputHintAttributes {
+    #[deluxe(default)]
+    autocomplete: Option<String>,
+    #[deluxe(default)]
+    inputmode: Option<String>,
+    #[deluxe(default)]
+    spellcheck: Option<bool>,
+    #[deluxe(default)]
+    autocapitalize: Option<String>,
+    #[deluxe(default)]
+    readonly: Option<bool>,
+    #[deluxe(default)]
+    size: Option<usize>,
+}
+
+impl ToQuote for InputHintAttributes {
+    fn to_quote(&self) -> TokenStream {
+        let autocomplete = self.autocomplete.as_ref().map(|v| quote::quote! { #v.to_string() });
+        let inputmode = self.inputmode.as_ref().map(|v| quote::quote! { #v.to_string() });
+        let autocapitalize = self.autocapitalize.as_ref().map(|v| quote::quote! { #v.to_string() });
+        let autocomplete = autocomplete.to_quote();
+        let inputmode = inputmode.to_quote();
+        let spellcheck = self.spellcheck.to_quote();
+        let autocapitalize = autocapitalize.to_quote();
+        let readonly = self.readonly.unwrap_or(false);
+        let size = self.size.to_quote();
+        quote::quote! {
+            form_fields::elements::InputHints {
+                autocomplete: #autocomplete,
+                inputmode: #inputmode,
+                spellcheck: #spellcheck,
+                autocapitalize: #autocapitalize,
+                readonly: #readonly,
+                size: #size,
+            }
+        }
+    }
+}
+
+/// Checks the pattern at compile time if the `regex` feature is enabled.
+/// Otherwise an invalid pattern panics when the spec is created.
+pub(crate) fn parse_pattern(pattern: Option<syn::LitStr>) -> deluxe::Result<TokenStream> {
+    let Some(pattern) = pattern else {
+        return Ok(quote::quote! { None });
+    };
+
+    #[cfg(feature = "regex")]
+    if let Err(err) = regex::Regex::new(&format!("^(?:{})$", pattern.value())) {
+        return Err(syn::Error::new(pattern.span(), err));
+    }
+
+    Ok(quote::quote! {
+        Some(form_fields::__pattern!(#pattern))
+    })
+}
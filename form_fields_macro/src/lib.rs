@@ -1,11 +1,17 @@
 use core::panic;
+use std::collections::BTreeMap;
 use proc_macro2::TokenStream;
 use selectable::selectable;
 use syn::parse_macro_input;
 use syn::{DeriveInput, Field, GenericArgument, Ident, PathArguments, Type, spanned::Spanned};
 
 mod checkbox;
+mod civil;
 mod date_picker;
+mod decimal_field;
+mod email_field;
+mod form;
+mod hints;
 mod multiselect;
 mod number_field;
 mod password_field;
@@ -13,6 +19,9 @@ mod radio_button;
 mod select;
 mod selectable;
 mod text_field;
+mod textarea;
+mod time_picker;
+mod url_field;
 mod passthrough;
 pub(crate) mod to_quote;
 
@@ -34,23 +43,100 @@ pub(crate) mod to_quote;
 ///   - `max_length`: Maximum number of characters allowed in the input.
 ///   - `min_length`: Minimum number of characters required in the input.
 ///   - `placeholder`: Placeholder text displayed inside the input field.
+///   - `pattern`: A regular expression the whole value has to match. Also checked on the server.
+///     Requires the `regex` feature.
+///   - `suggestions`: Values offered in a `<datalist>`, e.g. `suggestions = ["Berlin", "Zurich"]`.
+///     Can also be set at runtime on the descriptor.
+///   - `restrict_to_suggestions`: Only accept one of the suggestions.
+///
+/// #### `#[textarea]`
+/// - **Description**: Represents a multi-line text input. Line endings are normalized to `\n`.
+/// - **HTML Element**: [`<textarea>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea)
+/// - **Supported Types**: `String`, or `Vec<String>` for every non-empty line
+/// - **Parameters**:
+///   - `rows`, `cols`: Visible size of the text area.
+///   - `max_length`, `min_length`: Limits of the whole text.
+///   - `wrap`: `soft`, `hard` or `off`.
+///   - `placeholder`: Placeholder text displayed inside the text area.
+///
+/// #### `#[email_field]`
+/// - **Description**: Represents an email input field. The address syntax is checked on the server.
+/// - **HTML Input Type**: [`<input type="email">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/email)
+/// - **Supported Types**: `String`, or `Vec<String>` for a comma-separated list (`multiple`)
+/// - **Parameters**:
+///   - `max_length`: Maximum number of characters allowed in the input.
+///   - `placeholder`: Placeholder text displayed inside the input field.
+///   - `lowercase_domain`: Lowercases the domain of the address.
+///   - `punycode`: Converts internationalized domains to punycode. Doesn't compile without the `idna` feature.
+///
+/// #### `#[url_field]`
+/// - **Description**: Represents a URL input field. Requires the `url` feature.
+/// - **HTML Input Type**: [`<input type="url">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/url)
+/// - **Supported Types**: `url::Url`, re-exported as `form_fields::elements::url_field::Url`
+/// - **Parameters**:
+///   - `schemes`: Allowed schemes, e.g. `["https"]`. By default, every scheme except
+///     `javascript:`, `data:` and `vbscript:` is allowed.
+///   - `allowed_hosts`: If set, the host has to be one of these or a subdomain of them.
+///   - `denied_hosts`: Rejected hosts, including their subdomains.
+///   - `placeholder`: Placeholder text displayed inside the input field.
+///
+/// #### `#[password_field]`
+/// - **Description**: Represents a password input field.
+/// - **HTML Input Type**: [`<input type="password">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/password)
+/// - **Supported Types**: `String`
+/// - **Parameters**: `max_length`, `min_length` and `pattern`, like `#[text_field]`.
 ///
 /// #### `#[number_field]`
 /// - **Description**: Represents a number input field.
 /// - **HTML Input Type**: [`<input type="number">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/number)
-/// - **Supported Types**: `u8`, `i32`, `f64`, etc.
+/// - **Supported Types**: `u8`, `i32`, `f64`, etc., up to `i128` and `u128`.
 /// - **Parameters**:
-///   - `min`: Minimum value allowed for the input.
+///   - `min`: Minimum value allowed for the input, e.g. `min = -0.5`.
+///     Values out of range for the field type don't compile.
 ///   - `max`: Maximum value allowed for the input.
+///   - `step`: Step between valid values, counted from `min` or zero, or `"any"`.
+///     Float fields without a step accept any value.
+///   - `locale`: Accepts and renders the separators of `"en"` (`1,234.5`), `"de"` (`1.234,5`),
+///     `"fr"` (`1 234,5`) or `"ch"` (`1'234.5`) in a text input. Off by default.
+///
+/// #### `#[decimal_field]`
+/// - **Description**: Represents a number input field for exact decimals. Doesn't compile without the `decimal` feature.
+/// - **HTML Input Type**: [`<input type="number">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/number)
+/// - **Supported Types**: `rust_decimal::Decimal`, re-exported as `form_fields::elements::decimal_field::Decimal`
+/// - **Parameters**:
+///   - `min`: Minimum value allowed, as a decimal literal, e.g. `min = 0.50`.
+///   - `max`: Maximum value allowed, as a decimal literal.
+///   - `step`: Step between valid values, counted from `min` or zero.
+///   - `scale`: Maximum number of decimal places.
 ///
 /// #### `#[date_select]`
 /// - **Description**: Represents a date picker input field.
 /// - **HTML Input Type**: [`<input type="date">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/date)
-/// - **Supported Types**: `chrono::NaiveDate`
+/// - **Supported Types**: `chrono::NaiveDate`, `time::Date` or `jiff::civil::Date`, with the feature
+///   of the same name enabled on `form_fields`
 /// - **Parameters**:
 ///   - `min`: Minimum date allowed (formatted as `YYYY-MM-DD`).
 ///   - `max`: Maximum date allowed (formatted as `YYYY-MM-DD`).
 ///
+///   Bounds are checked at compile time, including whether the date type can represent them.
+///
+/// #### `#[time_select]`, `#[datetime_select]`, `#[month_select]`, `#[week_select]`
+/// - **Description**: Represent time, local date and time, month and week picker input fields.
+///   Times are accepted with and without seconds.
+/// - **HTML Input Type**: [`<input type="time">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/time),
+///   [`datetime-local`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/datetime-local),
+///   [`month`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/month),
+///   [`week`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/week)
+/// - **Supported Types**: The time, date and time, and date types of `chrono`, `time` or `jiff`.
+///   Months are stored as their first day and weeks as their Monday.
+/// - **Parameters**:
+///   - `min`: Minimum value allowed, formatted as `HH:MM[:SS]`, `YYYY-MM-DDTHH:MM[:SS]`,
+///     `YYYY-MM` or `YYYY-Www`.
+///   - `max`: Maximum value allowed, formatted like `min`.
+///   - `step`: Step in seconds for times, in months or in weeks, counted from `min`.
+///
+///   Like dates, the bounds are checked at compile time.
+///
 /// #### `#[checkbox]`
 /// - **Description**: Represents a checkbox input field.
 /// - **HTML Input Type**: [`<input type="checkbox">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/checkbox)
@@ -64,15 +150,18 @@ pub(crate) mod to_quote;
 /// - **HTML Input Type**: [`<input type="radio">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/radio)
 /// - **Supported Types**: Any type that implements the `Selectable` trait. Pre-implemented for primitives and `String`.
 /// - **Parameters**:
-///   - `options`: A list of selectable options.
+///   - `options`: A list of selectable options, or `all` for every variant of a derived `Selectable` enum.
+///   - `options_from`: A type implementing `OptionsSource`, loading the options on each request instead.
 ///   - `default_value`: The default selected option.
 ///
 /// #### `#[select]`
 /// - **Description**: Represents a dropdown select input field.
+///   Options with a `Selectable::group` are rendered in an `<optgroup>`.
 /// - **HTML Input Type**: [`<select>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select)
 /// - **Supported Types**: T: `Selectable`
 /// - **Parameters**:
-///   - `options`: A list of selectable options.
+///   - `options`: A list of selectable options, or `all` for every variant of a derived `Selectable` enum.
+///   - `options_from`: A type implementing `OptionsSource`, loading the options on each request instead.
 ///   - `default_value`: The default selected option.
 ///   - `placeholder`: Placeholder text displayed when no option is selected.
 ///
@@ -81,13 +170,48 @@ pub(crate) mod to_quote;
 /// - **HTML Input Type**: [`<input type="checkbox">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/checkbox)
 /// - **Supported Types**: T: `Selectable`
 /// - **Parameters**:
-///   - `options`: A list of selectable options.
+///   - `options`: A list of selectable options, or `all` for every variant of a derived `Selectable` enum.
+///   - `options_from`: A type implementing `OptionsSource`, loading the options on each request instead.
+///   - `mode`: `"checkboxes"` (default), `"select"` for a native `<select multiple>` or `"switches"` for a switch list.
+///   - `size`: Number of visible rows, only with `mode = "select"`.
 ///
 /// ### Base Field Attributes
 /// The following attributes can be used with any of the above field types:
 /// - `display_name`: A string to display as the label for the field.
 /// - `field_name`: A string to use as the name of the field in the form data. Defaults to the field's identifier.
 /// - `help_text`: Additional text to display as help for the field.
+/// - `attrs`: Extra HTML attributes of the input element, e.g. `attrs(class = "wide", hx_get = "/check")`.
+///   Underscores in names are replaced by hyphens. Classes are added to the classes of the theme.
+///   Reserved attributes like `id`, `name`, `type`, `value` or `required` are ignored,
+///   and attributes the field already renders, like `maxlength`, are never replaced.
+///
+/// ### Input Hints
+/// Text, email, URL, password and number fields also accept hints for the browser,
+/// e.g. for autofill and password managers. They aren't checked on the server.
+/// - `autocomplete`: e.g. `"username"` or `"new-password"`.
+/// - `inputmode`: The virtual keyboard to show, e.g. `"numeric"`.
+/// - `spellcheck`: `true` or `false`.
+/// - `autocapitalize`: e.g. `"off"` or `"words"`.
+/// - `readonly`: Makes the input read-only.
+/// - `size`: Visible width of the input in characters.
+///
+/// ### Form Attributes
+/// `#[form(...)]` on the struct sets the defaults of the `meta` field of the generated spec,
+/// which holds the settings of the rendered `<form>` element:
+/// - `action`: The URL the form is submitted to.
+/// - `method`: The HTTP method, `GET` or `POST`. Defaults to `POST`.
+/// - `enctype`: Overrides the encoding type. `multipart/form-data` is used automatically if a field requires it.
+/// - `csrf`: The name of the hidden input holding a CSRF token.
+///   Set the expected token with `meta.set_csrf` and check the submitted one with `meta.verify_csrf()`.
+/// - `hidden`: Names of additional hidden inputs.
+/// - `submit`: The label of the submit button.
+///
+/// Submitted CSRF and hidden inputs are stored in `meta` instead of being rejected.
+///
+/// `#[form(...)]` on a field sets its placement when rendering the complete form:
+/// - `section`: Groups the field with others of the same section in a `<fieldset>`.
+/// - `row`: Renders the field side by side with others of the same row.
+/// - `order`: Sorts the fields. Fields with the same order keep their declaration order.
 ///
 /// ### Example Usage
 /// ```rust
@@ -108,18 +232,36 @@ pub(crate) mod to_quote;
 ///
 /// This will generate a `TestFormSpec` struct with fields wrapped in `form_fields::FormField`.
 /// The generated struct can be used for rendering, parsing, and validating form data.
+/// Every field gets a unique id made from the struct and field name, e.g. `test-text`.
+/// `autofocus_first_invalid()` focuses the first field with an error after validation.
+/// With the `maud` feature, the generated struct renders the complete `<form>`.
+/// `error_summary()` collects every error, including `meta.errors`, and renders
+/// them as links to the invalid fields.
+/// `set_layout()` arranges the label, input, help text and error of every field
+/// with a `form_fields::render::maud::FieldLayout`.
+/// `context()` exports every field as a `form_fields::render::context::FieldContext`
+/// in declaration order, for rendering with template engines.
 #[proc_macro_derive(
     FromForm,
     attributes(
         text_field,
         number_field,
+        decimal_field,
         date_select,
+        time_select,
+        datetime_select,
+        month_select,
+        week_select,
         checkbox,
         radio_button,
         select,
         multiselect,
         password_field,
+        textarea,
+        email_field,
+        url_field,
         passthrough,
+        form,
     )
 )]
 pub fn from_form(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -132,31 +274,106 @@ pub fn from_form(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
-/// Derive macro for generating selectable types from a copyable struct.
-#[proc_macro_derive(Selectable)]
+/// Derive macro for selectable types.
+///
+/// For enums with unit variants, it also implements `Display` and `FromStr` with the key of each
+/// variant, `maud::Render` with its label and adds an `ALL` constant, usable as `options = all`.
+/// Structs use the fields marked with `#[selectable(key)]` and `#[selectable(label)]`.
+/// Any other type is its own key and display value.
+///
+/// ### Enum Attributes
+/// - `rename_all`: Case convention of the keys, one of `lowercase`, `UPPERCASE`, `snake_case`,
+///   `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`, `camelCase` or `PascalCase`.
+///   Defaults to the variant name.
+///
+/// ### Variant Attributes
+/// - `key`: The submitted value of the option.
+/// - `label`: The displayed text of the option. Defaults to the variant name.
+///
+/// ### Field Attributes
+/// - `key`: The field holding the key, any `Clone + PartialEq + ToString + FromStr` type.
+/// - `label`: The field holding the displayed text, any `Clone + Display` type. Defaults to the key.
+///
+/// Both fields are cloned, as `Selectable` returns the key and label by value.
+///
+/// ```rust
+/// use form_fields::Selectable;
+///
+/// #[derive(Clone, PartialEq, Debug, Selectable)]
+/// #[selectable(rename_all = "kebab-case")]
+/// enum Cars {
+///     Audi,
+///     #[selectable(label = "BMW")]
+///     Bmw,
+///     #[selectable(key = "benz")]
+///     MercedesBenz,
+/// }
+///
+/// assert_eq!(Cars::MercedesBenz.to_string(), "benz");
+/// assert_eq!("bmw".parse(), Ok(Cars::Bmw));
+/// assert_eq!(Cars::ALL.len(), 3);
+///
+/// #[derive(Clone, Selectable)]
+/// struct Category {
+///     #[selectable(key)]
+///     id: i64,
+///     #[selectable(label)]
+///     name: String,
+/// }
+/// ```
+///
+/// A key without `Clone` doesn't compile:
+/// ```compile_fail
+/// use form_fields::Selectable;
+///
+/// #[derive(PartialEq)]
+/// struct Id(i64);
+/// # impl std::fmt::Display for Id {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { self.0.fmt(f) }
+/// # }
+/// # impl std::str::FromStr for Id {
+/// #     type Err = std::num::ParseIntError;
+/// #     fn from_str(s: &str) -> Result<Self, Self::Err> { s.parse().map(Id) }
+/// # }
+///
+/// #[derive(Selectable)]
+/// struct Category {
+///     #[selectable(key)]
+///     id: Id,
+/// }
+/// # impl Clone for Category {
+/// #     fn clone(&self) -> Self { Category { id: Id(self.id.0) } }
+/// # }
+/// ```
+#[proc_macro_derive(Selectable, attributes(selectable))]
 pub fn selectable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
-    let ident = input.ident.clone();
-
-    let expanded = selectable(&ident);
-
-    expanded.into()
+    match selectable(input) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
 }
 
-fn extract_fields(ast: &mut DeriveInput) -> deluxe::Result<Vec<FieldParseResult>> {
+fn extract_fields(
+    ast: &mut DeriveInput,
+) -> deluxe::Result<(Vec<FieldParseResult>, Vec<TokenStream>)> {
     let data = match &mut ast.data {
         syn::Data::Struct(data) => data,
         _ => panic!("FromForm can only be derived for structs"),
     };
 
     let mut fields = Vec::new();
+    let mut placements = Vec::new();
 
     for field in data.fields.iter_mut() {
+        if let Some(placement) = form::parse_placement(field)? {
+            placements.push(placement);
+        }
         let field = parse_field(field)?;
         fields.push(field);
     }
 
-    Ok(fields)
+    Ok((fields, placements))
 }
 
 fn generate_struct(name: &Ident, fields: &[FieldParseResult]) -> TokenStream {
@@ -169,6 +386,7 @@ fn generate_struct(name: &Ident, fields: &[FieldParseResult]) -> TokenStream {
             #(
                 pub #field_names: form_fields::FormField<#field_types>,
             )*
+            pub meta: form_fields::meta::FormMeta,
         }
     };
 
@@ -181,6 +399,17 @@ fn generate_from_request(
     fields: &[FieldParseResult],
 ) -> TokenStream {
     let idents: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
+    let field_types: Vec<&TokenStream> = fields.iter().map(|f| &f.field_type).collect();
+    let sourced: Vec<(&Ident, &syn::Path, &syn::Type)> = fields
+        .iter()
+        .filter_map(|f| {
+            let (source, option_type) = f.options_from.as_ref()?;
+            Some((&f.ident, source, option_type))
+        })
+        .collect();
+    let source_idents: Vec<&Ident> = sourced.iter().map(|(ident, _, _)| *ident).collect();
+    let sources: Vec<&syn::Path> = sourced.iter().map(|(_, source, _)| *source).collect();
+    let option_types: Vec<&syn::Type> = sourced.iter().map(|(_, _, ty)| *ty).collect();
 
     let text = quote::quote! {
         impl form_fields::from_form::FormSpecable for #original {
@@ -188,6 +417,8 @@ fn generate_from_request(
         }
 
         impl form_fields::from_form::FormSpec for #newtype {
+            const MULTIPART: bool = false #(|| <#field_types as form_fields::Descriptor>::MULTIPART)*;
+
             fn generate_spec() -> Self {
                 Self::new()
             }
@@ -200,16 +431,36 @@ fn generate_from_request(
                     } else
                 )*
                 {
-                    false
+                    self.meta.parse_field(name, value)
                 }
             }
         }
+
+        impl<S: Send + Sync> form_fields::from_form::PopulateOptions<S> for #newtype
+        where
+            #( #sources: form_fields::from_form::OptionsSource<S, Option = #option_types>, )*
+        {
+            async fn populate_options(&mut self, state: &S) {
+                #(
+                    self.#source_idents.descriptor.options =
+                        <#sources as form_fields::from_form::OptionsSource<S>>::options(state).await;
+                )*
+            }
+        }
+
+        form_fields::__impl_render_form!(#newtype { #(#idents),* });
     };
 
     text
 }
 
-fn generate_impl(newtype: &Ident, origin: &Ident, fields: &[FieldParseResult]) -> TokenStream {
+fn generate_impl(
+    newtype: &Ident,
+    origin: &Ident,
+    fields: &[FieldParseResult],
+    meta: &TokenStream,
+    placements: &[TokenStream],
+) -> TokenStream {
     let display_names: Vec<String> = fields
         .iter()
         .map(|f| {
@@ -222,9 +473,16 @@ fn generate_impl(newtype: &Ident, origin: &Ident, fields: &[FieldParseResult]) -
         .iter()
         .map(|f| f.field_name.clone().unwrap_or_else(|| f.ident.to_string()))
         .collect();
+    let form_id = kebab_case(&origin.to_string());
+    let ids: Vec<String> = field_names
+        .iter()
+        .map(|field_name| format!("{}-{}", form_id, field_name))
+        .collect();
     let idents: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
     let help_text: Vec<&TokenStream> = fields.iter().map(|f| &f.help_text).collect();
+    let attributes: Vec<&TokenStream> = fields.iter().map(|f| &f.attributes).collect();
     let initializers: Vec<&TokenStream> = fields.iter().map(|f| &f.initializer).collect();
+    let field_types: Vec<&TokenStream> = fields.iter().map(|f| &f.field_type).collect();
     let required: Vec<bool> = fields.iter().map(|f| f.required).collect();
 
     let required_fields: Vec<_> = fields
@@ -245,16 +503,25 @@ fn generate_impl(newtype: &Ident, origin: &Ident, fields: &[FieldParseResult]) -
                     let #idents = form_fields::FormField {
                         display_name: #display_names,
                         field_name: #field_names,
+                        id: #ids,
                         descriptor: #initializers,
                         intermediate: std::default::Default::default(),
                         required: #required,
                         error: None,
                         help_text: #help_text,
+                        theme: None,
+                        autofocus: false,
+                        attributes: #attributes,
+                        layout: None,
                     };
                 )*
 
+                let mut meta = #meta;
+                meta.placements = vec![#(#placements),*];
+
                 Self {
                     #(#idents,)*
+                    meta,
                 }
             }
 
@@ -305,6 +572,67 @@ fn generate_impl(newtype: &Ident, origin: &Ident, fields: &[FieldParseResult]) -
                 Some(())
             }
 
+            /// Focuses the first field with an error once the page is loaded.
+            fn autofocus_first_invalid(&mut self) {
+                #(
+                    if self.#idents.error.is_some() {
+                        self.#idents.autofocus = true;
+                        return;
+                    }
+                )*
+            }
+
+            /// Renders every field of this form with the given theme.
+            fn set_theme(&mut self, theme: &'static form_fields::render::theme::Theme) {
+                #(
+                    self.#idents.theme = Some(theme);
+                )*
+                self.meta.theme = Some(theme);
+            }
+
+            /// Collects the form-level errors and the errors of every field, in declaration order.
+            fn error_summary(&self) -> form_fields::meta::ErrorSummary {
+                let mut entries: Vec<form_fields::meta::ErrorEntry> = self
+                    .meta
+                    .errors
+                    .iter()
+                    .map(|error| form_fields::meta::ErrorEntry {
+                        message: error.clone(),
+                        label: None,
+                        id: None,
+                    })
+                    .collect();
+                #(
+                    if let Some(error) = &self.#idents.error {
+                        entries.push(form_fields::meta::ErrorEntry {
+                            message: error.clone(),
+                            label: Some(self.#idents.display_name),
+                            id: (!<#field_types as form_fields::Descriptor>::HIDDEN).then(|| self.#idents.id()),
+                        });
+                    }
+                )*
+                form_fields::meta::ErrorSummary {
+                    theme: self.meta.theme(),
+                    entries,
+                }
+            }
+
+            /// Arranges every field of this form with the given layout.
+            fn set_layout(&mut self, layout: form_fields::render::Layout) {
+                #(
+                    self.#idents.layout = Some(layout);
+                )*
+            }
+
+            /// Exports every field as a template view model, in declaration order.
+            fn context(&self) -> Vec<form_fields::render::context::FieldContext> {
+                vec![
+                    #(
+                        <form_fields::render::context::Context as form_fields::render::Renderer<#field_types>>::render(&self.#idents),
+                    )*
+                ]
+            }
+
             fn load(&mut self, input: #origin) {
                 #(
                     self.#required_fields.intermediate =
@@ -329,11 +657,19 @@ fn form_derive(item: TokenStream) -> deluxe::Result<TokenStream> {
     let origin = ast.ident.clone();
     let newtype = quote::format_ident!("{}{}", origin, "FormSpec");
 
-    let fields = extract_fields(&mut ast)?;
+    let meta = form::parse_meta(&mut ast)?;
+    let (fields, placements) = extract_fields(&mut ast)?;
+
+    if let Some(field) = fields.iter().find(|f| f.ident == "meta") {
+        return Err(syn::Error::new(
+            field.ident.span(),
+            "`meta` is reserved for the form settings of the generated spec",
+        ));
+    }
 
     let r#struct = generate_struct(&newtype, &fields);
     let r#from_request = generate_from_request(&newtype, &origin, &fields);
-    let r#impl = generate_impl(&newtype, &origin, &fields);
+    let r#impl = generate_impl(&newtype, &origin, &fields, &meta, &placements);
 
     let text = quote::quote! {
         #r#struct
@@ -352,10 +688,28 @@ pub(crate) struct FieldParseResult {
     display_name: Option<String>,
     field_name: Option<String>,
     help_text: TokenStream,
+    /// Type implementing `OptionsSource`, loading the options at request time,
+    /// and the type of the options.
+    options_from: Option<(syn::Path, syn::Type)>,
+    attributes: TokenStream,
     field_type: TokenStream,
     initializer: TokenStream,
 }
 
+/// Options are either listed statically or loaded by an `OptionsSource`.
+pub(crate) fn check_options(
+    options: &selectable::SelectOptions,
+    options_from: Option<&syn::Path>,
+) -> deluxe::Result<()> {
+    match options_from {
+        Some(options_from) if !options.is_empty() => Err(syn::Error::new_spanned(
+            options_from,
+            "`options` and `options_from` can't be combined",
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[derive(deluxe::ParseMetaItem)]
 pub(crate) struct BaseField {
     #[deluxe(default)]
@@ -364,6 +718,29 @@ pub(crate) struct BaseField {
     field_name: Option<String>,
     #[deluxe(default)]
     help_text: Option<String>,
+    #[deluxe(default)]
+    attrs: BTreeMap<Ident, String>,
+}
+
+impl BaseField {
+    /// Generates the extra HTML attributes of the field.
+    /// Underscores in names are replaced by hyphens, e.g. `data_controller` becomes `data-controller`.
+    fn attributes(&self) -> TokenStream {
+        let names = self
+            .attrs
+            .keys()
+            .map(|name| name.to_string().replace('_', "-"));
+        let values = self.attrs.values();
+        quote::quote! {
+            {
+                let mut attributes = form_fields::render::attributes::Attributes::default();
+                #(
+                    attributes.set(#names, #values);
+                )*
+                attributes
+            }
+        }
+    }
 }
 
 fn parse_field(field: &mut Field) -> deluxe::Result<FieldParseResult> {
@@ -375,10 +752,26 @@ fn parse_field(field: &mut Field) -> deluxe::Result<FieldParseResult> {
         return Ok(number_field);
     }
 
+    if let Some(decimal_field) = decimal_field::try_parse(field, &ident, &field_type, required)? {
+        return Ok(decimal_field);
+    }
+
     if let Some(text_field) = text_field::try_parse(field, &ident, &field_type, required)? {
         return Ok(text_field);
     }
 
+    if let Some(textarea) = textarea::try_parse(field, &ident, &field_type, required)? {
+        return Ok(textarea);
+    }
+
+    if let Some(email_field) = email_field::try_parse(field, &ident, &field_type, required)? {
+        return Ok(email_field);
+    }
+
+    if let Some(url_field) = url_field::try_parse(field, &ident, &field_type, required)? {
+        return Ok(url_field);
+    }
+
     if let Some(password_field) = password_field::try_parse(field, &ident, &field_type, required)? {
         return Ok(password_field);
     }
@@ -387,6 +780,10 @@ fn parse_field(field: &mut Field) -> deluxe::Result<FieldParseResult> {
         return Ok(date_picker);
     }
 
+    if let Some(time_picker) = time_picker::try_parse(field, &ident, &field_type, required)? {
+        return Ok(time_picker);
+    }
+
     if let Some(checkbox) = checkbox::try_parse(field, &ident, &field_type, required)? {
         return Ok(checkbox);
     }
@@ -414,8 +811,11 @@ fn parse_field(field: &mut Field) -> deluxe::Result<FieldParseResult> {
 }
 
 fn extract_option_inner(ty: &Type) -> deluxe::Result<(bool, Type)> {
-    let Type::Path(type_path) = ty else {
-        return deluxe::Result::Err(syn::Error::new(ty.span(), "Expected a type path"));
+    let type_path = match ty {
+        Type::Path(type_path) => type_path,
+        // Options like `(i64, String)` can't be optional.
+        Type::Tuple(_) => return Ok((true, ty.clone())),
+        _ => return deluxe::Result::Err(syn::Error::new(ty.span(), "Expected a type path")),
     };
 
     let Some(segment) = type_path.path.segments.first() else {
@@ -448,6 +848,24 @@ fn extract_option_inner(ty: &Type) -> deluxe::Result<(bool, Type)> {
     Ok((false, inner_type.clone()))
 }
 
+/// Converts a type name like `SignUpForm` into `sign-up-form`.
+fn kebab_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                result.push('-');
+            }
+            result.extend(c.to_lowercase());
+        } else if c == '_' {
+            result.push('-');
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
 // https://github.com/jf2048/deluxe/issues/24#issuecomment-2518421372
 pub(crate) fn maybe_extract_attribute<T, R>(t: &mut T) -> deluxe::Result<Option<R>>
 where
@@ -1,11 +1,46 @@
-use crate::{BaseField, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+use proc_macro2::TokenStream;
 
+use crate::{BaseField, selectable::SelectOptions, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+
+// Example #[multiselect(options = [...], mode = "select", size = 8)]
 #[derive(deluxe::ExtractAttributes)]
 #[deluxe(attributes(multiselect))]
 struct MultiSelectAttributes {
     #[deluxe(flatten)]
     base: BaseField,
-    options: Vec<syn::Expr>,
+    #[deluxe(default)]
+    options: SelectOptions,
+    options_from: Option<syn::Path>,
+    mode: Option<syn::LitStr>,
+    size: Option<usize>,
+}
+
+fn quote_mode(mode: Option<syn::LitStr>, size: Option<usize>) -> deluxe::Result<TokenStream> {
+    let span = mode
+        .as_ref()
+        .map_or_else(proc_macro2::Span::call_site, syn::LitStr::span);
+    let mode = mode.map_or_else(|| "checkboxes".to_string(), |mode| mode.value());
+    let variant = match (mode.as_str(), size) {
+        ("checkboxes", None) => quote::quote! { Checkboxes },
+        ("switches", None) => quote::quote! { Switches },
+        ("select", size) => {
+            let size = size.to_quote();
+            quote::quote! { Select { size: #size } }
+        }
+        ("checkboxes" | "switches", Some(_)) => {
+            return Err(syn::Error::new(
+                span,
+                "`size` is only supported with mode = \"select\"",
+            ));
+        }
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                "expected one of \"checkboxes\", \"select\" or \"switches\"",
+            ));
+        }
+    };
+    Ok(quote::quote! { form_fields::elements::multiselect::MultiSelectMode::#variant })
 }
 
 pub(crate) fn try_parse(
@@ -31,20 +66,27 @@ pub(crate) fn try_parse(
             return Ok(None);
         };
 
+        crate::check_options(&attrs.options, attrs.options_from.as_ref())?;
         let help_text = attrs.base.help_text.to_quote();
-        let options = attrs.options.iter().collect::<Vec<_>>();
+        let options = attrs.options.to_quote(field_type);
+        let mode = quote_mode(attrs.mode, attrs.size)?;
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: attrs
+                .options_from
+                .map(|options_from| (options_from, field_type.clone())),
             field_type: quote::quote! {
                 form_fields::elements::MultiSelect::<#field_type>
             },
             initializer: quote::quote! {
                 form_fields::elements::MultiSelect::<#field_type> {
-                    options: vec![ #( #options ),* ],
+                    options: #options,
+                    mode: #mode,
                 }
             },
         }))
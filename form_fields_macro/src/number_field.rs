@@ -1,16 +1,162 @@
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
 use crate::{
-    BaseField, FieldParseResult, maybe_extract_attribute,
-    to_quote::{ToQuote, ToQuoteAs},
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
 };
 
 // Example #[number_field(min = 0, max = 120)]
+// Example #[number_field(min = -0.5, step = 0.25)]
 #[derive(deluxe::ExtractAttributes)]
 #[deluxe(attributes(number_field))]
 struct NumberFieldAttributes {
     #[deluxe(flatten)]
     base: BaseField,
-    min: Option<isize>,
-    max: Option<isize>,
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+    step: Option<syn::Expr>,
+    locale: Option<syn::LitStr>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
+}
+
+const INTEGER_TYPES: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+const FLOAT_TYPES: [&str; 2] = ["f32", "f64"];
+
+/// The name of the field type if it is one of the given primitives.
+fn primitive(field_type: &syn::Type, types: &[&'static str]) -> Option<&'static str> {
+    let syn::Type::Path(path) = field_type else {
+        return None;
+    };
+    types.iter().copied().find(|ty| path.path.is_ident(ty))
+}
+
+fn is_integer(field_type: &syn::Type) -> bool {
+    primitive(field_type, &INTEGER_TYPES).is_some()
+}
+
+/// Checks that the expression is a numeric literal, optionally negated,
+/// and that it has no fraction if the field holds integers.
+fn numeric_literal<'a>(
+    expr: &'a syn::Expr,
+    field_type: &syn::Type,
+) -> deluxe::Result<&'a syn::Expr> {
+    let lit = match expr {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => expr.as_ref(),
+        expr => expr,
+    };
+    match lit {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(_),
+            ..
+        }) if is_integer(field_type) => Err(syn::Error::new_spanned(
+            expr,
+            "expected an integer for an integer field",
+        )),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(_) | syn::Lit::Float(_),
+            ..
+        }) => Ok(expr),
+        _ => Err(syn::Error::new_spanned(expr, "expected a number")),
+    }
+}
+
+/// Suffixes the literal with the primitive field type, so `min = 0` works for floats as well
+/// and a value out of range for the type fails to compile instead of wrapping.
+/// Literals for other types, e.g. aliases, are left to type inference.
+fn typed_literal(expr: &syn::Expr, field_type: &syn::Type) -> TokenStream {
+    let (negative, lit) = match expr {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, expr.as_ref()),
+        expr => (false, expr),
+    };
+    let syn::Expr::Lit(syn::ExprLit { lit, .. }) = lit else {
+        return quote::quote! { #expr };
+    };
+    let lit = match (
+        lit,
+        primitive(field_type, &INTEGER_TYPES),
+        primitive(field_type, &FLOAT_TYPES),
+    ) {
+        (syn::Lit::Int(int), Some(ty), _) => syn::Lit::Int(syn::LitInt::new(
+            &format!("{}{ty}", int.base10_digits()),
+            int.span(),
+        )),
+        (syn::Lit::Int(int), _, Some(ty)) => syn::Lit::Float(syn::LitFloat::new(
+            &format!("{}.0{ty}", int.base10_digits()),
+            int.span(),
+        )),
+        (syn::Lit::Float(float), _, Some(ty)) => syn::Lit::Float(syn::LitFloat::new(
+            &format!("{}{ty}", float.base10_digits()),
+            float.span(),
+        )),
+        _ => return quote::quote! { #expr },
+    };
+    if negative {
+        quote::quote_spanned! {expr.span()=> -#lit }
+    } else {
+        quote::quote! { #lit }
+    }
+}
+
+fn quote_number(expr: Option<syn::Expr>, field_type: &syn::Type) -> deluxe::Result<TokenStream> {
+    let Some(expr) = expr else {
+        return Ok(quote::quote! { None });
+    };
+    let expr = typed_literal(numeric_literal(&expr, field_type)?, field_type);
+    Ok(quote::quote! { Some(#expr) })
+}
+
+fn quote_step(expr: Option<syn::Expr>, field_type: &syn::Type) -> deluxe::Result<TokenStream> {
+    match &expr {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(str),
+            ..
+        })) => {
+            if !str.value().eq_ignore_ascii_case("any") {
+                return Err(syn::Error::new(str.span(), "expected a number or \"any\""));
+            }
+            Ok(quote::quote! { Some(form_fields::elements::number_field::Step::Any) })
+        }
+        Some(expr) => {
+            let expr = typed_literal(numeric_literal(expr, field_type)?, field_type);
+            Ok(quote::quote! {
+                Some(form_fields::elements::number_field::Step::Value(#expr))
+            })
+        }
+        None => Ok(quote::quote! { None }),
+    }
+}
+
+/// Maps a language code to one of the `NumberLocale` presets.
+fn quote_locale(locale: Option<syn::LitStr>) -> deluxe::Result<TokenStream> {
+    let Some(locale) = locale else {
+        return Ok(quote::quote! { None });
+    };
+    let preset = match locale.value().to_ascii_lowercase().as_str() {
+        "en" => quote::quote! { EN },
+        "de" => quote::quote! { DE },
+        "fr" => quote::quote! { FR },
+        "ch" => quote::quote! { CH },
+        _ => {
+            return Err(syn::Error::new(
+                locale.span(),
+                "expected one of \"en\", \"de\", \"fr\" or \"ch\"",
+            ));
+        }
+    };
+    Ok(quote::quote! { Some(form_fields::elements::NumberLocale::#preset) })
 }
 
 pub(crate) fn try_parse(
@@ -21,14 +167,19 @@ pub(crate) fn try_parse(
 ) -> deluxe::Result<Option<FieldParseResult>> {
     if let Some(attrs) = maybe_extract_attribute::<_, NumberFieldAttributes>(field)? {
         let help_text = attrs.base.help_text.to_quote();
-        let min = attrs.min.to_quote_as(field_type);
-        let max = attrs.max.to_quote_as(field_type);
+        let min = quote_number(attrs.min, field_type)?;
+        let max = quote_number(attrs.max, field_type)?;
+        let step = quote_step(attrs.step, field_type)?;
+        let locale = quote_locale(attrs.locale)?;
+        let hints = attrs.hints.to_quote();
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: None,
             field_type: quote::quote! {
                 form_fields::elements::NumberField::<#field_type>
             },
@@ -36,6 +187,9 @@ pub(crate) fn try_parse(
                 form_fields::elements::NumberField::<#field_type> {
                     min: #min,
                     max: #max,
+                    step: #step,
+                    locale: #locale,
+                    hints: #hints,
                 }
             },
         }))
@@ -22,9 +22,11 @@ pub(crate) fn try_parse(
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: None,
             field_type: quote::quote! {
                 form_fields::elements::Passthrough::<#field_type>
             },
@@ -1,4 +1,7 @@
-use crate::{BaseField, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+use crate::{
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
+};
 
 // Example #[password_field(max_length = 5)]
 #[derive(deluxe::ExtractAttributes)]
@@ -8,6 +11,9 @@ struct PasswordFieldAttributes {
     base: BaseField,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    pattern: Option<syn::LitStr>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
 }
 
 pub(crate) fn try_parse(
@@ -20,12 +26,16 @@ pub(crate) fn try_parse(
         let help_text = attrs.base.help_text.to_quote();
         let min_length = attrs.min_length.to_quote();
         let max_length = attrs.max_length.to_quote();
+        let pattern = crate::hints::parse_pattern(attrs.pattern)?;
+        let hints = attrs.hints.to_quote();
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: None,
             field_type: quote::quote! {
                 form_fields::elements::PasswordField
             },
@@ -33,6 +43,8 @@ pub(crate) fn try_parse(
                 form_fields::elements::PasswordField {
                     min_length: #min_length,
                     max_length: #max_length,
+                    pattern: #pattern,
+                    hints: #hints,
                 }
             },
         }))
@@ -1,11 +1,13 @@
-use crate::{BaseField, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+use crate::{BaseField, selectable::SelectOptions, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
 
 #[derive(deluxe::ExtractAttributes)]
 #[deluxe(attributes(radio_button))]
 struct RadioButtonAttributes {
     #[deluxe(flatten)]
     base: BaseField,
-    options: Vec<syn::Expr>,
+    #[deluxe(default)]
+    options: SelectOptions,
+    options_from: Option<syn::Path>,
     default_value: syn::Expr,
 }
 
@@ -16,21 +18,26 @@ pub(crate) fn try_parse(
     required: bool,
 ) -> deluxe::Result<Option<FieldParseResult>> {
     if let Some(attrs) = maybe_extract_attribute::<_, RadioButtonAttributes>(field)? {
+        crate::check_options(&attrs.options, attrs.options_from.as_ref())?;
         let help_text = attrs.base.help_text.to_quote();
-        let options = attrs.options;
+        let options = attrs.options.to_quote(field_type);
         let default_value = attrs.default_value;
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: attrs
+                .options_from
+                .map(|options_from| (options_from, field_type.clone())),
             field_type: quote::quote! {
                 form_fields::elements::RadioButton::<#field_type>
             },
             initializer: quote::quote! {
                 form_fields::elements::RadioButton::<#field_type> {
-                    options: vec![ #( #options ),* ],
+                    options: #options,
                     default_value: #default_value,
                 }
             },
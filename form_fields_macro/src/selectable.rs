@@ -1,14 +1,320 @@
-pub(crate) fn selectable(ident: &proc_macro2::Ident) -> proc_macro2::TokenStream {
-    quote::quote! {
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
+use crate::maybe_extract_attribute;
+
+// Example #[selectable(rename_all = "kebab-case")]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(selectable))]
+struct SelectableAttributes {
+    #[deluxe(default)]
+    rename_all: Option<syn::LitStr>,
+}
+
+// Example #[selectable(key)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(selectable))]
+struct FieldAttributes {
+    #[deluxe(default)]
+    key: bool,
+    #[deluxe(default)]
+    label: bool,
+}
+
+// Example #[selectable(key = "bmw", label = "BMW")]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(selectable))]
+struct VariantAttributes {
+    #[deluxe(default)]
+    key: Option<String>,
+    #[deluxe(default)]
+    label: Option<String>,
+}
+
+/// The options of a select, radio button or multiselect field.
+/// Either a list of expressions or `all`, for every variant of a derived `Selectable` enum.
+pub(crate) enum SelectOptions {
+    All,
+    List(Vec<syn::Expr>),
+}
+
+impl Default for SelectOptions {
+    fn default() -> Self {
+        Self::List(Vec::new())
+    }
+}
+
+impl deluxe::ParseMetaItem for SelectOptions {
+    fn parse_meta_item(
+        input: syn::parse::ParseStream,
+        mode: deluxe::ParseMode,
+    ) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.fork().parse::<syn::Ident>()? == "all" {
+            input.parse::<syn::Ident>()?;
+            return Ok(Self::All);
+        }
+        Ok(Self::List(<Vec<syn::Expr>>::parse_meta_item(input, mode)?))
+    }
+}
+
+impl SelectOptions {
+    pub(crate) fn is_empty(&self) -> bool {
+        matches!(self, Self::List(options) if options.is_empty())
+    }
+
+    /// Generates the `Vec` of options for a field holding `field_type`.
+    pub(crate) fn to_quote(&self, field_type: &syn::Type) -> TokenStream {
+        match self {
+            Self::All => quote::quote! { <#field_type>::ALL.to_vec() },
+            Self::List(options) => quote::quote! { vec![ #( #options ),* ] },
+        }
+    }
+}
+
+pub(crate) fn selectable(mut input: syn::DeriveInput) -> deluxe::Result<TokenStream> {
+    let attrs = maybe_extract_attribute::<_, SelectableAttributes>(&mut input)?;
+    let ident = &input.ident;
+
+    let syn::Data::Enum(data) = &mut input.data else {
+        if let Some(attrs) = attrs {
+            return Err(syn::Error::new(
+                attrs
+                    .rename_all
+                    .map_or(ident.span(), |rename_all| rename_all.span()),
+                "`rename_all` is only supported on enums",
+            ));
+        }
+        return fields(&mut input);
+    };
+    if data
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, syn::Fields::Unit))
+    {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Selectable can only be derived for enums with unit variants",
+        ));
+    }
+    if data.variants.is_empty() || !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Selectable can't be derived for empty or generic enums",
+        ));
+    }
+
+    let rename_all = attrs.and_then(|attrs| attrs.rename_all);
+    let mut variants = Vec::new();
+    let mut keys = Vec::new();
+    let mut labels = Vec::new();
+    for variant in data.variants.iter_mut() {
+        let attrs = maybe_extract_attribute::<_, VariantAttributes>(variant)?;
+        let (key, label) = attrs.map_or((None, None), |attrs| (attrs.key, attrs.label));
+        let name = variant.ident.to_string();
+        let key = match (key, &rename_all) {
+            (Some(key), _) => key,
+            (None, Some(rename_all)) => rename(&name, rename_all)?,
+            (None, None) => name.clone(),
+        };
+        if keys.contains(&key) {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!("duplicate key \"{key}\""),
+            ));
+        }
+        variants.push(&variant.ident);
+        keys.push(key);
+        labels.push(label.unwrap_or(name));
+    }
+
+    Ok(quote::quote! {
+        impl #ident {
+            /// Every variant, in declaration order.
+            pub const ALL: &'static [Self] = &[ #( Self::#variants ),* ];
+        }
+
         impl form_fields::selectable::Selectable for #ident {
+            type Key = Self;
+            type DisplayValue = &'static str;
+
+            fn key(&self) -> Self::Key {
+                match self {
+                    #( Self::#variants => Self::#variants, )*
+                }
+            }
+
+            fn display_value(&self) -> Self::DisplayValue {
+                match self {
+                    #( Self::#variants => #labels, )*
+                }
+            }
+        }
+
+        impl std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    #( Self::#variants => #keys, )*
+                })
+            }
+        }
+
+        impl std::str::FromStr for #ident {
+            type Err = form_fields::selectable::UnknownOption;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #( #keys => Ok(Self::#variants), )*
+                    _ => Err(form_fields::selectable::UnknownOption),
+                }
+            }
+        }
+
+        form_fields::__impl_render_selectable!(#ident);
+    })
+}
+
+/// Selectable for a struct with `#[selectable(key)]` and `#[selectable(label)]` fields.
+/// The label defaults to the key.
+fn fields(input: &mut syn::DeriveInput) -> deluxe::Result<TokenStream> {
+    let mut key = None;
+    let mut label = None;
+    if let syn::Data::Struct(data) = &mut input.data {
+        for (index, field) in data.fields.iter_mut().enumerate() {
+            let Some(attrs) = maybe_extract_attribute::<_, FieldAttributes>(field)? else {
+                continue;
+            };
+            let member = field
+                .ident
+                .clone()
+                .map_or_else(|| syn::Member::Unnamed(index.into()), syn::Member::Named);
+            for (marked, slot, name) in [
+                (attrs.key, &mut key, "key"),
+                (attrs.label, &mut label, "label"),
+            ] {
+                if !marked {
+                    continue;
+                }
+                if slot.is_some() {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        format!("only one field can be the {name}"),
+                    ));
+                }
+                *slot = Some((member.clone(), field.ty.clone()));
+            }
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Some((key, key_type)) = key else {
+        if label.is_some() {
+            return Err(syn::Error::new(
+                ident.span(),
+                "a `#[selectable(label)]` requires a `#[selectable(key)]` field",
+            ));
+        }
+        return Ok(cloned(input));
+    };
+    let (label, label_type) = label.unwrap_or_else(|| (key.clone(), key_type.clone()));
+    // The trait returns owned values, so the fields are cloned. Naming the field types
+    // reports a missing `Clone` on the field instead of a mismatch with a reference.
+    let clone_key = quote::quote_spanned! {key_type.span()=>
+        <#key_type as ::core::clone::Clone>::clone(&self.#key)
+    };
+    let clone_label = quote::quote_spanned! {label_type.span()=>
+        <#label_type as ::core::clone::Clone>::clone(&self.#label)
+    };
+    Ok(quote::quote! {
+        impl #impl_generics form_fields::selectable::Selectable for #ident #ty_generics #where_clause {
+            type Key = #key_type;
+            type DisplayValue = #label_type;
+
+            fn key(&self) -> Self::Key {
+                #clone_key
+            }
+
+            fn display_value(&self) -> Self::DisplayValue {
+                #clone_label
+            }
+        }
+    })
+}
+
+/// Selectable for any other type, which is its own key and display value.
+fn cloned(input: &syn::DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote::quote! {
+        impl #impl_generics form_fields::selectable::Selectable for #ident #ty_generics #where_clause {
             type Key = Self;
             type DisplayValue = Self;
             fn key(&self) -> Self::Key {
-                *self
+                self.clone()
             }
             fn display_value(&self) -> Self::DisplayValue {
-                *self
+                self.clone()
             }
         }
     }
 }
+
+/// Splits a variant name like `HttpServer` into its lowercase words.
+fn words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let boundary = c.is_uppercase()
+            && i > 0
+            && (!chars[i - 1].is_uppercase()
+                || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+        if (boundary || c == '_') && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if c != '_' {
+            word.extend(c.to_lowercase());
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn rename(name: &str, rename_all: &syn::LitStr) -> deluxe::Result<String> {
+    let words = words(name);
+    let capitalize = |word: &String| {
+        let mut chars = word.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_uppercase().chain(chars).collect()
+        })
+    };
+    Ok(match rename_all.value().as_str() {
+        "lowercase" => words.concat(),
+        "UPPERCASE" => words.concat().to_uppercase(),
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+        "kebab-case" => words.join("-"),
+        "SCREAMING-KEBAB-CASE" => words.join("-").to_uppercase(),
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.clone()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        "PascalCase" => words.iter().map(capitalize).collect(),
+        _ => {
+            return Err(syn::Error::new(
+                rename_all.span(),
+                "expected one of \"lowercase\", \"UPPERCASE\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \
+                \"kebab-case\", \"SCREAMING-KEBAB-CASE\", \"camelCase\" or \"PascalCase\"",
+            ));
+        }
+    })
+}
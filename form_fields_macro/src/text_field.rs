@@ -1,6 +1,9 @@
-use crate::{BaseField, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+use crate::{
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
+};
 
-// Example #[text_field(max_length = 5, placeholder = "Enter text")]
+// Example #[text_field(max_length = 5, placeholder = "Enter text", suggestions = ["Berlin", "Zurich"])]
 #[derive(deluxe::ExtractAttributes)]
 #[deluxe(attributes(text_field))]
 struct TextFieldAttributes {
@@ -8,7 +11,13 @@ struct TextFieldAttributes {
     base: BaseField,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    pattern: Option<syn::LitStr>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
     placeholder: Option<String>,
+    #[deluxe(default)]
+    suggestions: Vec<syn::LitStr>,
+    restrict_to_suggestions: Option<bool>,
 }
 
 pub(crate) fn try_parse(
@@ -21,13 +30,19 @@ pub(crate) fn try_parse(
         let help_text = attrs.base.help_text.to_quote();
         let min_length = attrs.min_length.to_quote();
         let max_length = attrs.max_length.to_quote();
+        let pattern = crate::hints::parse_pattern(attrs.pattern)?;
+        let hints = attrs.hints.to_quote();
         let placeholder = attrs.placeholder.to_quote();
+        let suggestions = attrs.suggestions;
+        let restrict_to_suggestions = attrs.restrict_to_suggestions.unwrap_or(false);
         Ok(Some(FieldParseResult {
             ident: ident.clone(),
             required,
+            attributes: attrs.base.attributes(),
             display_name: attrs.base.display_name,
             field_name: attrs.base.field_name,
             help_text,
+            options_from: None,
             field_type: quote::quote! {
                 form_fields::elements::TextField
             },
@@ -35,7 +50,11 @@ pub(crate) fn try_parse(
                 form_fields::elements::TextField {
                     min_length: #min_length,
                     max_length: #max_length,
+                    pattern: #pattern,
+                    hints: #hints,
                     placeholder: #placeholder,
+                    suggestions: vec![ #( #suggestions.to_string() ),* ],
+                    restrict_to_suggestions: #restrict_to_suggestions,
                 }
             },
         }))
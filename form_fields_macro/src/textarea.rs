@@ -0,0 +1,64 @@
+use crate::{BaseField, FieldParseResult, maybe_extract_attribute, to_quote::ToQuote};
+
+// Example #[textarea(rows = 5, max_length = 500, wrap = "hard")]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(textarea))]
+struct TextAreaAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    rows: Option<usize>,
+    cols: Option<usize>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    wrap: Option<String>,
+    placeholder: Option<String>,
+}
+
+pub(crate) fn try_parse(
+    field: &mut syn::Field,
+    ident: &syn::Ident,
+    field_type: &syn::Type,
+    required: bool,
+) -> deluxe::Result<Option<FieldParseResult>> {
+    if let Some(attrs) = maybe_extract_attribute::<_, TextAreaAttributes>(field)? {
+        let help_text = attrs.base.help_text.to_quote();
+        let rows = attrs.rows.to_quote();
+        let cols = attrs.cols.to_quote();
+        let min_length = attrs.min_length.to_quote();
+        let max_length = attrs.max_length.to_quote();
+        let wrap = attrs
+            .wrap
+            .map(|wrap| quote::quote! { #wrap.to_string() })
+            .to_quote();
+        let placeholder = attrs
+            .placeholder
+            .map(|placeholder| quote::quote! { #placeholder.to_string() })
+            .to_quote();
+        Ok(Some(FieldParseResult {
+            ident: ident.clone(),
+            required,
+            attributes: attrs.base.attributes(),
+            display_name: attrs.base.display_name,
+            field_name: attrs.base.field_name,
+            help_text,
+            options_from: None,
+            field_type: quote::quote! {
+                form_fields::elements::TextArea::<#field_type>
+            },
+            initializer: quote::quote! {
+                {
+                    let mut textarea = form_fields::elements::TextArea::<#field_type>::default();
+                    textarea.placeholder = #placeholder;
+                    textarea.rows = #rows;
+                    textarea.cols = #cols;
+                    textarea.min_length = #min_length;
+                    textarea.max_length = #max_length;
+                    textarea.wrap = #wrap;
+                    textarea
+                }
+            },
+        }))
+    } else {
+        Ok(None)
+    }
+}
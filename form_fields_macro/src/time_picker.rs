@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    BaseField, FieldParseResult,
+    civil::{Format, quote_bound},
+    maybe_extract_attribute,
+    to_quote::ToQuote,
+};
+
+// Example #[time_select(min = "09:00", max = "17:30", step = 900)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(time_select))]
+struct TimePickerAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    min: Option<syn::LitStr>,
+    max: Option<syn::LitStr>,
+    step: Option<u32>,
+}
+
+// Example #[datetime_select(min = "2024-01-01T00:00", step = 60)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(datetime_select))]
+struct DateTimePickerAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    min: Option<syn::LitStr>,
+    max: Option<syn::LitStr>,
+    step: Option<u32>,
+}
+
+// Example #[month_select(min = "2024-01", max = "2024-12")]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(month_select))]
+struct MonthPickerAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    min: Option<syn::LitStr>,
+    max: Option<syn::LitStr>,
+    step: Option<u32>,
+}
+
+// Example #[week_select(min = "2024-W01", step = 2)]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(week_select))]
+struct WeekPickerAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    min: Option<syn::LitStr>,
+    max: Option<syn::LitStr>,
+    step: Option<u32>,
+}
+
+fn result(
+    base: BaseField,
+    ident: &syn::Ident,
+    required: bool,
+    field_type: TokenStream,
+    min: TokenStream,
+    max: TokenStream,
+    step: Option<u32>,
+) -> FieldParseResult {
+    let help_text = base.help_text.to_quote();
+    let step = step.to_quote();
+    FieldParseResult {
+        ident: ident.clone(),
+        required,
+        attributes: base.attributes(),
+        display_name: base.display_name,
+        field_name: base.field_name,
+        help_text,
+        options_from: None,
+        initializer: quote::quote! {
+            #field_type {
+                min: #min,
+                max: #max,
+                step: #step,
+            }
+        },
+        field_type,
+    }
+}
+
+pub(crate) fn try_parse(
+    field: &mut syn::Field,
+    ident: &syn::Ident,
+    field_type: &syn::Type,
+    required: bool,
+) -> deluxe::Result<Option<FieldParseResult>> {
+    if let Some(attrs) = maybe_extract_attribute::<_, TimePickerAttributes>(field)? {
+        return Ok(Some(result(
+            attrs.base,
+            ident,
+            required,
+            quote::quote! { form_fields::elements::TimePicker::<#field_type> },
+            quote_bound(attrs.min, Format::Time, field_type),
+            quote_bound(attrs.max, Format::Time, field_type),
+            attrs.step,
+        )));
+    }
+
+    if let Some(attrs) = maybe_extract_attribute::<_, DateTimePickerAttributes>(field)? {
+        return Ok(Some(result(
+            attrs.base,
+            ident,
+            required,
+            quote::quote! { form_fields::elements::DateTimePicker::<#field_type> },
+            quote_bound(attrs.min, Format::DateTime, field_type),
+            quote_bound(attrs.max, Format::DateTime, field_type),
+            attrs.step,
+        )));
+    }
+
+    if let Some(attrs) = maybe_extract_attribute::<_, MonthPickerAttributes>(field)? {
+        return Ok(Some(result(
+            attrs.base,
+            ident,
+            required,
+            quote::quote! { form_fields::elements::MonthPicker::<#field_type> },
+            quote_bound(attrs.min, Format::Month, field_type),
+            quote_bound(attrs.max, Format::Month, field_type),
+            attrs.step,
+        )));
+    }
+
+    if let Some(attrs) = maybe_extract_attribute::<_, WeekPickerAttributes>(field)? {
+        return Ok(Some(result(
+            attrs.base,
+            ident,
+            required,
+            quote::quote! { form_fields::elements::WeekPicker::<#field_type> },
+            quote_bound(attrs.min, Format::Week, field_type),
+            quote_bound(attrs.max, Format::Week, field_type),
+            attrs.step,
+        )));
+    }
+
+    Ok(None)
+}
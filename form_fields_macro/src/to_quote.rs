@@ -1,4 +1,3 @@
-use chrono::Datelike;
 use proc_macro2::TokenStream;
 
 pub(crate) trait ToQuote {
@@ -16,31 +15,3 @@ where
         }
     }
 }
-
-pub(crate) trait ToQuoteAs {
-    fn to_quote_as(&self, ident: &syn::Type) -> TokenStream;
-}
-
-impl ToQuoteAs for Option<isize> {
-    fn to_quote_as(&self, ident: &syn::Type) -> TokenStream {
-        match self {
-            Some(value) => quote::quote! { Some(#value as #ident) },
-            None => quote::quote! { None },
-        }
-    }
-}
-
-impl ToQuoteAs for Option<chrono::NaiveDate> {
-    fn to_quote_as(&self, ident: &syn::Type) -> TokenStream {
-        match self {
-            Some(value) => {
-                let year = value.year();
-                let month = value.month();
-                let day = value.day();
-
-                quote::quote! { Some(chrono::NaiveDate::from_ymd(#year, #month, #day) as #ident) }
-            }
-            None => quote::quote! { None },
-        }
-    }
-}
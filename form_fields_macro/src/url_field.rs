@@ -0,0 +1,63 @@
+use crate::{
+    BaseField, FieldParseResult, hints::InputHintAttributes, maybe_extract_attribute,
+    to_quote::ToQuote,
+};
+
+// Example #[url_field(schemes = ["https"], allowed_hosts = ["example.com"])]
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(url_field))]
+struct UrlFieldAttributes {
+    #[deluxe(flatten)]
+    base: BaseField,
+    placeholder: Option<String>,
+    #[deluxe(default)]
+    schemes: Vec<String>,
+    #[deluxe(default)]
+    allowed_hosts: Vec<String>,
+    #[deluxe(default)]
+    denied_hosts: Vec<String>,
+    #[deluxe(flatten)]
+    hints: InputHintAttributes,
+}
+
+pub(crate) fn try_parse(
+    field: &mut syn::Field,
+    ident: &syn::Ident,
+    _field_type: &syn::Type,
+    required: bool,
+) -> deluxe::Result<Option<FieldParseResult>> {
+    if let Some(attrs) = maybe_extract_attribute::<_, UrlFieldAttributes>(field)? {
+        let help_text = attrs.base.help_text.to_quote();
+        let placeholder = attrs
+            .placeholder
+            .map(|placeholder| quote::quote! { #placeholder.to_string() })
+            .to_quote();
+        let schemes = attrs.schemes;
+        let allowed_hosts = attrs.allowed_hosts;
+        let denied_hosts = attrs.denied_hosts;
+        let hints = attrs.hints.to_quote();
+        Ok(Some(FieldParseResult {
+            ident: ident.clone(),
+            required,
+            attributes: attrs.base.attributes(),
+            display_name: attrs.base.display_name,
+            field_name: attrs.base.field_name,
+            help_text,
+            options_from: None,
+            field_type: quote::quote! {
+                form_fields::elements::UrlField
+            },
+            initializer: quote::quote! {
+                form_fields::elements::UrlField {
+                    placeholder: #placeholder,
+                    schemes: vec![#(#schemes.to_string()),*],
+                    allowed_hosts: vec![#(#allowed_hosts.to_string()),*],
+                    denied_hosts: vec![#(#denied_hosts.to_string()),*],
+                    hints: #hints,
+                }
+            },
+        }))
+    } else {
+        Ok(None)
+    }
+}